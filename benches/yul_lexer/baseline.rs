@@ -0,0 +1,205 @@
+//!
+//! The baseline regex-based Yul lexer.
+//!
+//! A reduced copy of the lexer replaced by the byte scanner. It does the same work per token,
+//! that is, the whitespace and symbol regex search, and the integer and identifier matching with
+//! the regexes compiled on each call, but only counts the tokens instead of building them.
+//!
+
+/// The whitespace and symbol regex.
+const SYMBOL_REGEX: &str = r"(\s+)|(:=)|(\->)|[{}(),:]|(/\*)|(\*/)";
+
+/// The keywords, except for the sized integer types.
+const KEYWORDS: [&str; 15] = [
+    "object", "code", "function", "let", "if", "switch", "case", "default", "for", "break",
+    "continue", "leave", "true", "false", "bool",
+];
+
+/// The symbols.
+const SYMBOLS: [&str; 8] = [":=", "->", "{", "}", "(", ")", ",", ":"];
+
+///
+/// The baseline regex-based Yul lexer.
+///
+pub struct Lexer {
+    /// The input source code.
+    input: String,
+    /// The tokenization regular expression.
+    regexp: regex::Regex,
+    /// The position in the source code.
+    index: usize,
+    /// The line of the current position.
+    line: usize,
+    /// The column of the current position.
+    column: usize,
+}
+
+impl Lexer {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(mut input: String) -> Self {
+        input.push('\n');
+
+        Self {
+            input,
+            regexp: regex::Regex::new(SYMBOL_REGEX).expect("Regexp is valid"),
+            index: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    ///
+    /// Lexes the whole input, returning the number of tokens without the end of file.
+    ///
+    pub fn count(mut self) -> anyhow::Result<usize> {
+        let mut count = 0;
+        while self.next()? {
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    ///
+    /// Advances the lexer, returning whether a token has been found before the end of file.
+    ///
+    fn next(&mut self) -> anyhow::Result<bool> {
+        loop {
+            if let Some(length) = Self::comment(&self.input[self.index..]) {
+                self.shift(length);
+                continue;
+            }
+
+            if let Some(length) = Self::string(&self.input[self.index..]) {
+                self.shift(length);
+                return Ok(true);
+            }
+
+            let r#match = match self.regexp.find(&self.input[self.index..]) {
+                Some(r#match) => r#match,
+                None => return Ok(false),
+            };
+
+            if r#match.start() != 0 {
+                let word = &self.input[self.index..self.index + r#match.start()];
+                if !Self::is_keyword(word) && !Self::is_integer(word) && !Self::is_identifier(word)
+                {
+                    anyhow::bail!("{}:{} Invalid lexeme `{}`", self.line, self.column, word);
+                }
+                self.shift(r#match.start());
+                return Ok(true);
+            }
+
+            let symbol = r#match.as_str();
+            if !symbol.trim().is_empty() {
+                if !SYMBOLS.contains(&symbol) {
+                    anyhow::bail!("{}:{} Invalid lexeme `{}`", self.line, self.column, symbol);
+                }
+                self.shift(symbol.len());
+                return Ok(true);
+            }
+
+            self.shift(symbol.len());
+        }
+    }
+
+    ///
+    /// Returns the comment's length, including the whitespace before it.
+    ///
+    fn comment(input: &str) -> Option<usize> {
+        let trimmed_start = input.trim_start();
+        let length = input.len() - trimmed_start.len();
+
+        let (start, end) = if trimmed_start.starts_with("//") {
+            ("//", "\n")
+        } else if trimmed_start.starts_with("/*") {
+            ("/*", "*/")
+        } else {
+            return None;
+        };
+        let body = &trimmed_start[start.len()..];
+        Some(
+            length
+                + start.len()
+                + body
+                    .find(end)
+                    .map(|position| position + end.len())
+                    .unwrap_or(body.len()),
+        )
+    }
+
+    ///
+    /// Returns the string literal's length, including the quotes.
+    ///
+    fn string(input: &str) -> Option<usize> {
+        let (is_hexadecimal, start) = if input.starts_with('"') {
+            (false, 1)
+        } else if input.starts_with(r#"hex""#) {
+            (true, r#"hex""#.len())
+        } else {
+            return None;
+        };
+
+        let bytes = input.as_bytes();
+        let mut length = start;
+        while length < bytes.len() && bytes[length] != b'"' {
+            match bytes[length] {
+                b'\n' | b'\r' => break,
+                b'\\' if !is_hexadecimal && length + 1 < bytes.len() => length += 2,
+                _ => length += 1,
+            }
+        }
+        Some((length + 1).min(bytes.len()))
+    }
+
+    ///
+    /// Checks whether the word is a keyword.
+    ///
+    fn is_keyword(word: &str) -> bool {
+        for prefix in ["int", "uint"] {
+            if let Some(bitlength) = word.strip_prefix(prefix) {
+                if bitlength.parse::<usize>().is_ok() {
+                    return true;
+                }
+            }
+        }
+        KEYWORDS.contains(&word)
+    }
+
+    ///
+    /// Checks whether the word is a decimal or hexadecimal integer literal.
+    ///
+    fn is_integer(word: &str) -> bool {
+        regex::Regex::new("^[0-9]+$")
+            .expect("Regexp is valid")
+            .is_match(word)
+            || regex::Regex::new(r#"^0x[0-9a-fA-F]+$"#)
+                .expect("Regexp is valid")
+                .is_match(word)
+    }
+
+    ///
+    /// Checks whether the word is an identifier.
+    ///
+    fn is_identifier(word: &str) -> bool {
+        regex::Regex::new(r"^[a-zA-Z_\$][a-zA-Z0-9_\$\.]*$")
+            .expect("Always valid")
+            .is_match(word)
+    }
+
+    ///
+    /// Advances the position by `length` bytes, updating the line and column.
+    ///
+    fn shift(&mut self, length: usize) {
+        for character in self.input[self.index..self.index + length].chars() {
+            if character == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.index += length;
+    }
+}
@@ -0,0 +1,69 @@
+//!
+//! The Yul lexer and parser benchmark.
+//!
+//! Compares the lexer with the baseline regex-based lexer it replaced, and measures the parser,
+//! on the `irOptimized` Yul output of solc. The input is `benches/data/erc20.yul` by default, and
+//! any other file, e.g. a contract dumped with `zksolc --dump-yul`, can be passed via the
+//! `YUL_BENCH_INPUT` environment variable.
+//!
+//! Run with `cargo bench --bench yul_lexer`.
+//!
+
+mod baseline;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use criterion::Throughput;
+
+/// The default input file.
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/benches/data/erc20.yul");
+
+///
+/// Benchmarks the lexers and the parser on the input file.
+///
+fn yul_lexer(criterion: &mut Criterion) {
+    let path = std::env::var("YUL_BENCH_INPUT").unwrap_or_else(|_| DEFAULT_INPUT.to_owned());
+    let input = std::fs::read_to_string(path.as_str())
+        .unwrap_or_else(|error| panic!("Input file `{}` reading error: {}", path, error));
+
+    let tokens = compiler_solidity::YulLexer::new(input.clone())
+        .collect::<anyhow::Result<Vec<compiler_solidity::YulToken>>>()
+        .expect("Lexical error");
+    let baseline_tokens = baseline::Lexer::new(input.clone())
+        .count()
+        .expect("Lexical error");
+    assert_eq!(
+        tokens.len(),
+        baseline_tokens,
+        "The lexers disagree on the token count of `{}`",
+        path
+    );
+
+    let mut group = criterion.benchmark_group("yul");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("baseline_lexer", |bencher| {
+        bencher.iter(|| {
+            baseline::Lexer::new(input.clone())
+                .count()
+                .expect("Lexical error")
+        })
+    });
+    group.bench_function("lexer", |bencher| {
+        bencher.iter(|| {
+            for token in compiler_solidity::YulLexer::new(input.clone()) {
+                token.expect("Lexical error");
+            }
+        })
+    });
+    group.bench_function("parser", |bencher| {
+        bencher.iter(|| {
+            let mut lexer = compiler_solidity::YulLexer::new(input.clone());
+            compiler_solidity::YulObject::parse(&mut lexer, None).expect("Syntax error");
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, yul_lexer);
+criterion_main!(benches);
@@ -0,0 +1,654 @@
+//!
+//! The zkEVM environment builtins, shared by the Yul and EVM legacy assembly translators.
+//!
+
+pub mod system_context_value;
+pub mod system_contract;
+pub mod transient_storage;
+pub mod trap;
+
+use inkwell::values::BasicValue;
+
+use self::system_context_value::SystemContextValue;
+
+///
+/// Translates the `callvalue` instruction, which reads the message value context register.
+///
+pub fn call_value<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    compiler_llvm_context::contract_context::get(
+        context,
+        compiler_common::ContextValue::MessageValue,
+    )
+}
+
+///
+/// Translates the `balance` instruction, which calls the ETH token `balanceOf`.
+///
+pub fn balance<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    address: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let balance = system_contract::static_call(
+        context,
+        system_contract::ADDRESS_ETH_TOKEN,
+        system_contract::SELECTOR_BALANCE_OF,
+        &[address],
+    )?;
+    Ok(Some(balance.as_basic_value_enum()))
+}
+
+///
+/// Translates the `selfbalance` instruction, which is `balance(address())`.
+///
+pub fn self_balance<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let address = compiler_llvm_context::contract_context::get(
+        context,
+        compiler_common::ContextValue::Address,
+    )?
+    .expect("Always exists")
+    .into_int_value();
+    balance(context, address)
+}
+
+///
+/// Translates the block and transaction context instructions, which call the system context
+/// getters.
+///
+pub fn system_context<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    value: SystemContextValue,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let value = system_contract::static_call(
+        context,
+        system_contract::ADDRESS_SYSTEM_CONTEXT,
+        value.selector(),
+        &[],
+    )?;
+    Ok(Some(value.as_basic_value_enum()))
+}
+
+///
+/// Translates the `blockhash` instruction, which calls the system context `getBlockHashEVM`.
+///
+/// Like in EVM, the hash is zero for the current and future blocks, and the blocks older than
+/// 256 blocks.
+///
+pub fn block_hash<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    number: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let hash = system_contract::static_call(
+        context,
+        system_contract::ADDRESS_SYSTEM_CONTEXT,
+        system_contract::SELECTOR_GET_BLOCK_HASH_EVM,
+        &[number],
+    )?;
+    Ok(Some(hash.as_basic_value_enum()))
+}
+
+///
+/// Translates the `blobhash` instruction.
+///
+/// The zkEVM transactions carry no blobs, so like in EVM for the indexes out of the transaction
+/// blob list, the hash is always zero.
+///
+pub fn blob_hash<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    _index: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    Ok(Some(context.field_const(0).as_basic_value_enum()))
+}
+
+///
+/// Translates the `codesize` instruction.
+///
+/// The zkEVM bytecode cannot be read, so the code is modeled as an empty program, which is
+/// followed in the deploy code by the constructor arguments, as the zkEVM passes them to the
+/// deploy code as the calldata. Thus, the code size is the calldata size in the deploy code, and
+/// zero in the runtime code, and the program size is always zero.
+///
+pub fn code_size<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    match context.code_type {
+        Some(compiler_llvm_context::CodeType::Deploy) => {
+            compiler_llvm_context::calldata::size(context)
+        }
+        Some(compiler_llvm_context::CodeType::Runtime) => {
+            Ok(Some(context.field_const(0).as_basic_value_enum()))
+        }
+        None => anyhow::bail!("The code type of `codesize` is undefined"),
+    }
+}
+
+///
+/// Translates the `codecopy` instruction, which copies the `size` bytes of the code at the
+/// `offset` to the `destination`.
+///
+/// By the code model described in `code_size`, the constructor arguments are copied from the
+/// calldata in the deploy code. The runtime code has no code to copy from, so the copies are
+/// rejected there rather than filled with zeros.
+///
+pub fn code_copy<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    offset: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    match context.code_type {
+        Some(compiler_llvm_context::CodeType::Deploy) => compiler_llvm_context::calldata::copy(
+            context,
+            [
+                destination.as_basic_value_enum(),
+                offset.as_basic_value_enum(),
+                size.as_basic_value_enum(),
+            ],
+        ),
+        Some(compiler_llvm_context::CodeType::Runtime) => anyhow::bail!(
+            "The runtime code of the contract `{}` cannot copy the code, as it is not modeled by the zkEVM code layout",
+            context.module().get_name().to_string_lossy()
+        ),
+        None => anyhow::bail!("The code type of `codecopy` is undefined"),
+    }
+}
+
+///
+/// Copies the `data` to the memory at the `destination`.
+///
+/// The data is stored word by word. The bytes of the last partial word are merged into the
+/// memory contents, so nothing is written past the end of the data.
+///
+pub fn data_copy<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    data: &[u8],
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    for (index, chunk) in data.chunks(compiler_common::SIZE_FIELD).enumerate() {
+        let padding_size = compiler_common::SIZE_FIELD - chunk.len();
+        let mut value = hex::encode(chunk);
+        value.push_str("00".repeat(padding_size).as_str());
+        let mut value = context.field_const_str_hex(value.as_str());
+
+        let destination = context.builder().build_int_add(
+            destination,
+            context.field_const((index * compiler_common::SIZE_FIELD) as u64),
+            format!("datacopy_destination_index_{}", index).as_str(),
+        );
+
+        if padding_size != 0 {
+            let mut mask = "00".repeat(chunk.len());
+            mask.push_str("ff".repeat(padding_size).as_str());
+            let original =
+                compiler_llvm_context::memory::load(context, [destination.as_basic_value_enum()])?
+                    .expect("Always exists");
+            let original = context.builder().build_and(
+                original.into_int_value(),
+                context.field_const_str_hex(mask.as_str()),
+                format!("datacopy_original_index_{}_masked", index).as_str(),
+            );
+            value = context.builder().build_or(
+                original,
+                value,
+                format!("datacopy_value_index_{}_merged", index).as_str(),
+            );
+        }
+
+        compiler_llvm_context::memory::store(
+            context,
+            [
+                destination.as_basic_value_enum(),
+                value.as_basic_value_enum(),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+///
+/// Translates the `extcodesize` instruction, which calls the account code storage
+/// `getCodeSize`.
+///
+/// Like in EVM, the size is zero for the accounts without code, including the contracts being
+/// constructed.
+///
+pub fn ext_code_size<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    address: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let size = system_contract::static_call(
+        context,
+        system_contract::ADDRESS_ACCOUNT_CODE_STORAGE,
+        system_contract::SELECTOR_GET_CODE_SIZE,
+        &[address],
+    )?;
+    Ok(Some(size.as_basic_value_enum()))
+}
+
+///
+/// Translates the `extcodehash` instruction, which calls the account code storage
+/// `getCodeHash`.
+///
+/// Like in EVM, the hash is zero for the non-existent accounts, and the empty data hash for the
+/// accounts without code.
+///
+pub fn ext_code_hash<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    address: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let hash = system_contract::static_call(
+        context,
+        system_contract::ADDRESS_ACCOUNT_CODE_STORAGE,
+        system_contract::SELECTOR_GET_CODE_HASH,
+        &[address],
+    )?;
+    Ok(Some(hash.as_basic_value_enum()))
+}
+
+///
+/// Translates the `extcodecopy` instruction, which fills the `size` bytes at the `destination`
+/// with zeros, as the zkEVM bytecode cannot be read by other contracts.
+///
+pub fn ext_code_copy<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fill_zeros(context, destination, size)?;
+    Ok(None)
+}
+
+///
+/// Translates the `mcopy` instruction, which copies the `size` bytes at the `source` to the
+/// `destination`.
+///
+/// The bytes are copied one by one, backwards if the destination follows the source, so the
+/// overlapping areas are copied as if through an intermediate buffer, like in EVM.
+///
+pub fn memory_copy<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    source: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let condition_block = context.append_basic_block("memory_copy_condition");
+    let body_block = context.append_basic_block("memory_copy_body");
+    let join_block = context.append_basic_block("memory_copy_join");
+
+    let is_backward = context.builder().build_int_compare(
+        inkwell::IntPredicate::UGT,
+        destination,
+        source,
+        "memory_copy_is_backward",
+    );
+    let counter_pointer = context.build_alloca(context.field_type(), "memory_copy_counter_pointer");
+    context.build_store(counter_pointer, context.field_const(0));
+    context.build_unconditional_branch(condition_block);
+
+    context.set_basic_block(condition_block);
+    let counter = context
+        .build_load(counter_pointer, "memory_copy_counter")
+        .into_int_value();
+    let condition = context.builder().build_int_compare(
+        inkwell::IntPredicate::ULT,
+        counter,
+        size,
+        "memory_copy_condition_compared",
+    );
+    context.build_conditional_branch(condition, body_block, join_block);
+
+    context.set_basic_block(body_block);
+    let last = context
+        .builder()
+        .build_int_sub(size, context.field_const(1), "memory_copy_last");
+    let counter_reversed =
+        context
+            .builder()
+            .build_int_sub(last, counter, "memory_copy_counter_reversed");
+    let index = context
+        .builder()
+        .build_select(
+            is_backward,
+            counter_reversed.as_basic_value_enum(),
+            counter.as_basic_value_enum(),
+            "memory_copy_index",
+        )
+        .into_int_value();
+    let source_offset = context
+        .builder()
+        .build_int_add(source, index, "memory_copy_source_offset");
+    let word = compiler_llvm_context::memory::load(context, [source_offset.as_basic_value_enum()])?
+        .expect("Always exists")
+        .into_int_value();
+    let byte = context.builder().build_right_shift(
+        word,
+        context.field_const(
+            (compiler_common::BITLENGTH_FIELD - compiler_common::BITLENGTH_BYTE) as u64,
+        ),
+        false,
+        "memory_copy_byte",
+    );
+    let destination_offset =
+        context
+            .builder()
+            .build_int_add(destination, index, "memory_copy_destination_offset");
+    compiler_llvm_context::memory::store_byte(
+        context,
+        [
+            destination_offset.as_basic_value_enum(),
+            byte.as_basic_value_enum(),
+        ],
+    )?;
+    let counter_next = context.builder().build_int_add(
+        counter,
+        context.field_const(1),
+        "memory_copy_counter_next",
+    );
+    context.build_store(counter_pointer, counter_next);
+    context.build_unconditional_branch(condition_block);
+
+    context.set_basic_block(join_block);
+    Ok(None)
+}
+
+///
+/// Fills the `size` bytes at the `destination` with zeros.
+///
+fn fill_zeros<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let condition_block = context.append_basic_block("fill_zeros_condition");
+    let body_block = context.append_basic_block("fill_zeros_body");
+    let join_block = context.append_basic_block("fill_zeros_join");
+
+    let index_pointer = context.build_alloca(context.field_type(), "fill_zeros_index_pointer");
+    context.build_store(index_pointer, context.field_const(0));
+    context.build_unconditional_branch(condition_block);
+
+    context.set_basic_block(condition_block);
+    let index = context
+        .build_load(index_pointer, "fill_zeros_index")
+        .into_int_value();
+    let condition = context.builder().build_int_compare(
+        inkwell::IntPredicate::ULT,
+        index,
+        size,
+        "fill_zeros_condition_compared",
+    );
+    context.build_conditional_branch(condition, body_block, join_block);
+
+    context.set_basic_block(body_block);
+    let offset = context
+        .builder()
+        .build_int_add(destination, index, "fill_zeros_offset");
+    compiler_llvm_context::memory::store_byte(
+        context,
+        [
+            offset.as_basic_value_enum(),
+            context.field_const(0).as_basic_value_enum(),
+        ],
+    )?;
+    let index_next =
+        context
+            .builder()
+            .build_int_add(index, context.field_const(1), "fill_zeros_index_next");
+    context.build_store(index_pointer, index_next);
+    context.build_unconditional_branch(condition_block);
+
+    context.set_basic_block(join_block);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::RwLock;
+
+    use compiler_llvm_context::WriteLLVM;
+    use num::Num;
+
+    use crate::evm::assembly::Assembly;
+    use crate::project::contract::source::Source;
+    use crate::project::Project;
+    use crate::yul::source_map::SourceMap;
+
+    use super::system_contract;
+
+    ///
+    /// Returns the unoptimized LLVM IR of the contract `source`.
+    ///
+    fn llvm_ir(source: Source, project: Project) -> String {
+        let version = project.version.to_owned();
+        let project = Arc::new(RwLock::new(project));
+
+        crate::initialize_target();
+        let llvm = inkwell::context::Context::create();
+        let target_machine = crate::target_machine(inkwell::OptimizationLevel::None)
+            .expect("Target machine initialization error");
+        let mut context = match source {
+            Source::Yul(_) => compiler_llvm_context::Context::new(
+                &llvm,
+                &target_machine,
+                inkwell::OptimizationLevel::None,
+                inkwell::OptimizationLevel::None,
+                "Test",
+                Some(project),
+                vec![],
+            ),
+            Source::EVM(_) => compiler_llvm_context::Context::new_evm(
+                &llvm,
+                &target_machine,
+                inkwell::OptimizationLevel::None,
+                inkwell::OptimizationLevel::None,
+                "Test",
+                Some(project),
+                vec![],
+                compiler_llvm_context::ContextEVMData::new(version),
+            ),
+        };
+        let mut source = source;
+        source.declare(&mut context).expect("Declaration error");
+        source.into_llvm(&mut context).expect("Definition error");
+        context.module().print_to_string().to_string()
+    }
+
+    ///
+    /// Returns the LLVM IR of the Yul contract, which stores `value` in its runtime code.
+    ///
+    fn yul_llvm_ir(value: &str) -> String {
+        let yul = format!(
+            r#"
+object "Test" {{
+    code {{ return(0, 0) }}
+    object "Test_deployed" {{
+        code {{ sstore(0, {}) }}
+    }}
+}}
+"#,
+            value
+        );
+        let project = Project::try_from_test_yul(yul.as_str()).expect("Invalid test input");
+        let source = project
+            .contracts
+            .get("Test")
+            .expect("Always exists")
+            .source
+            .to_owned();
+        llvm_ir(source, project)
+    }
+
+    ///
+    /// Returns the LLVM IR of the EVM legacy assembly contract, which stores the value pushed by
+    /// the `instructions` in its runtime code.
+    ///
+    fn evm_llvm_ir(instructions: &str) -> String {
+        let json = format!(
+            r#"{{
+    ".code": [ {{ "name": "STOP" }} ],
+    ".data": {{
+        "0": {{
+            ".code": [
+                {},
+                {{ "name": "PUSH", "value": "0" }},
+                {{ "name": "SSTORE" }},
+                {{ "name": "STOP" }}
+            ]
+        }}
+    }}
+}}"#,
+            instructions
+        );
+        let assembly: Assembly = serde_json::from_str(json.as_str()).expect("Invalid test input");
+        let project = Project::new(
+            semver::Version::new(0, 8, 0),
+            HashMap::new(),
+            HashMap::new(),
+            SourceMap::default(),
+        );
+        llvm_ir(Source::new_evm("Test".to_owned(), assembly), project)
+    }
+
+    ///
+    /// Returns the LLVM IR representation of the 256-bit constant, which is printed as a signed
+    /// integer.
+    ///
+    fn llvm_constant(value: &num::BigUint) -> String {
+        let modulus = num::BigUint::from(1u8) << (compiler_common::BITLENGTH_FIELD as u32);
+        if value.bit(compiler_common::BITLENGTH_FIELD as u64 - 1) {
+            format!("i256 -{}", modulus - value)
+        } else {
+            format!("i256 {}", value)
+        }
+    }
+
+    ///
+    /// Checks whether the LLVM IR calls the system contract at `address` with `selector`.
+    ///
+    /// The selector is written as the first call data word, padded with zeros on the right.
+    ///
+    fn calls_system_contract(llvm_ir: &str, address: u64, selector: &str) -> bool {
+        let selector = format!(
+            "{}{}",
+            selector,
+            "00".repeat(compiler_common::SIZE_FIELD - system_contract::SELECTOR_SIZE)
+        );
+        let selector =
+            num::BigUint::from_str_radix(selector.as_str(), compiler_common::BASE_HEXADECIMAL)
+                .expect("Always valid");
+        llvm_ir.contains(llvm_constant(&num::BigUint::from(address)).as_str())
+            && llvm_ir.contains(llvm_constant(&selector).as_str())
+    }
+
+    #[test]
+    fn yul_call_value_reads_context() {
+        let llvm_ir = yul_llvm_ir("callvalue()");
+        assert!(!calls_system_contract(
+            llvm_ir.as_str(),
+            system_contract::ADDRESS_ETH_TOKEN,
+            system_contract::SELECTOR_BALANCE_OF
+        ));
+        assert_ne!(llvm_ir, yul_llvm_ir("0"));
+    }
+
+    #[test]
+    fn yul_balance_calls_eth_token() {
+        let llvm_ir = yul_llvm_ir("balance(0x1234)");
+        assert!(calls_system_contract(
+            llvm_ir.as_str(),
+            system_contract::ADDRESS_ETH_TOKEN,
+            system_contract::SELECTOR_BALANCE_OF
+        ));
+        assert!(llvm_ir.contains(llvm_constant(&num::BigUint::from(0x1234u64)).as_str()));
+    }
+
+    #[test]
+    fn yul_self_balance_calls_eth_token() {
+        let llvm_ir = yul_llvm_ir("selfbalance()");
+        assert!(calls_system_contract(
+            llvm_ir.as_str(),
+            system_contract::ADDRESS_ETH_TOKEN,
+            system_contract::SELECTOR_BALANCE_OF
+        ));
+    }
+
+    #[test]
+    fn evm_call_value_reads_context() {
+        let llvm_ir = evm_llvm_ir(r#"{ "name": "CALLVALUE" }"#);
+        assert!(!calls_system_contract(
+            llvm_ir.as_str(),
+            system_contract::ADDRESS_ETH_TOKEN,
+            system_contract::SELECTOR_BALANCE_OF
+        ));
+        assert_ne!(llvm_ir, evm_llvm_ir(r#"{ "name": "PUSH", "value": "0" }"#));
+    }
+
+    #[test]
+    fn evm_balance_calls_eth_token() {
+        let llvm_ir = evm_llvm_ir(r#"{ "name": "PUSH", "value": "1234" }, { "name": "BALANCE" }"#);
+        assert!(calls_system_contract(
+            llvm_ir.as_str(),
+            system_contract::ADDRESS_ETH_TOKEN,
+            system_contract::SELECTOR_BALANCE_OF
+        ));
+        assert!(llvm_ir.contains(llvm_constant(&num::BigUint::from(0x1234u64)).as_str()));
+    }
+
+    #[test]
+    fn evm_self_balance_calls_eth_token() {
+        let llvm_ir = evm_llvm_ir(r#"{ "name": "SELFBALANCE" }"#);
+        assert!(calls_system_contract(
+            llvm_ir.as_str(),
+            system_contract::ADDRESS_ETH_TOKEN,
+            system_contract::SELECTOR_BALANCE_OF
+        ));
+    }
+}
@@ -0,0 +1,41 @@
+//!
+//! The system context value.
+//!
+
+///
+/// The block and transaction context value, provided by the system context contract getters.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemContextValue {
+    /// The `tx.origin` value.
+    Origin,
+    /// The `block.chainid` value.
+    ChainId,
+    /// The `tx.gasprice` value.
+    GasPrice,
+    /// The `block.gaslimit` value.
+    GasLimit,
+    /// The `block.coinbase` value.
+    CoinBase,
+    /// The `block.difficulty` value.
+    Difficulty,
+    /// The `block.basefee` value.
+    BaseFee,
+}
+
+impl SystemContextValue {
+    ///
+    /// Returns the selector of the system context getter function.
+    ///
+    pub fn selector(&self) -> &'static str {
+        match self {
+            Self::Origin => "938b5f32",
+            Self::ChainId => "9a8a0592",
+            Self::GasPrice => "fe173b97",
+            Self::GasLimit => "7877a797",
+            Self::CoinBase => "a6ae0aac",
+            Self::Difficulty => "19cae462",
+            Self::BaseFee => "6ef25c3a",
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//!
+//! The zkEVM system contract calls.
+//!
+
+use inkwell::values::BasicValue;
+
+/// The account code storage system contract address.
+pub const ADDRESS_ACCOUNT_CODE_STORAGE: u64 = 0x8002;
+
+/// The nonce holder system contract address.
+pub const ADDRESS_NONCE_HOLDER: u64 = 0x8003;
+
+/// The ETH token system contract address.
+pub const ADDRESS_ETH_TOKEN: u64 = 0x800a;
+
+/// The system context contract address.
+pub const ADDRESS_SYSTEM_CONTEXT: u64 = 0x800b;
+
+/// The account code storage `getCodeHash(uint256)` function selector.
+pub const SELECTOR_GET_CODE_HASH: &str = "e03fe177";
+
+/// The account code storage `getCodeSize(uint256)` function selector.
+pub const SELECTOR_GET_CODE_SIZE: &str = "1806aa18";
+
+/// The ETH token `balanceOf(uint256)` function selector.
+pub const SELECTOR_BALANCE_OF: &str = "9cc7f708";
+
+/// The nonce holder `getMinNonce(address)` function selector.
+pub const SELECTOR_GET_MIN_NONCE: &str = "896909dc";
+
+/// The system context `getBlockHashEVM(uint256)` function selector.
+pub const SELECTOR_GET_BLOCK_HASH_EVM: &str = "80b41246";
+
+/// The function selector size in bytes.
+pub const SELECTOR_SIZE: usize = 4;
+
+///
+/// Calls the system contract view function with a single return value.
+///
+/// The call data is written to the beginning of the heap, and the return value is written right
+/// after it. The heap words overwritten this way, including the solc scratch space and free
+/// memory pointer, are saved before the call and restored after it, so the call is transparent
+/// to the surrounding code. If the call fails, the return value is zero.
+///
+pub fn static_call<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    address: u64,
+    selector: &str,
+    arguments: &[inkwell::values::IntValue<'ctx>],
+) -> anyhow::Result<inkwell::values::IntValue<'ctx>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let input_size = SELECTOR_SIZE + arguments.len() * compiler_common::SIZE_FIELD;
+    // The selector shifts the arguments, so they span one more word, which is followed by the
+    // return value word.
+    let output_offset = (arguments.len() + 1) * compiler_common::SIZE_FIELD;
+    let words = arguments.len() + 2;
+
+    let mut saved = Vec::with_capacity(words);
+    for index in 0..words {
+        let offset = context.field_const((index * compiler_common::SIZE_FIELD) as u64);
+        let value = compiler_llvm_context::memory::load(context, [offset.as_basic_value_enum()])?
+            .expect("Always exists");
+        saved.push((offset, value));
+    }
+
+    let mut selector = selector.to_owned();
+    selector.push_str(
+        "00".repeat(compiler_common::SIZE_FIELD - SELECTOR_SIZE)
+            .as_str(),
+    );
+    compiler_llvm_context::memory::store(
+        context,
+        [
+            context.field_const(0).as_basic_value_enum(),
+            context
+                .field_const_str_hex(selector.as_str())
+                .as_basic_value_enum(),
+        ],
+    )?;
+    for (index, argument) in arguments.iter().enumerate() {
+        let offset = SELECTOR_SIZE + index * compiler_common::SIZE_FIELD;
+        compiler_llvm_context::memory::store(
+            context,
+            [
+                context.field_const(offset as u64).as_basic_value_enum(),
+                argument.as_basic_value_enum(),
+            ],
+        )?;
+    }
+    let output_offset = context.field_const(output_offset as u64);
+    compiler_llvm_context::memory::store(
+        context,
+        [
+            output_offset.as_basic_value_enum(),
+            context.field_const(0).as_basic_value_enum(),
+        ],
+    )?;
+
+    compiler_llvm_context::contract::call(
+        context,
+        compiler_llvm_context::IntrinsicFunction::StaticCall,
+        context.field_const(address),
+        None,
+        context.field_const(0),
+        context.field_const(input_size as u64),
+        output_offset,
+        context.field_const(compiler_common::SIZE_FIELD as u64),
+    )?;
+    let result =
+        compiler_llvm_context::memory::load(context, [output_offset.as_basic_value_enum()])?
+            .expect("Always exists");
+
+    for (offset, value) in saved.into_iter() {
+        compiler_llvm_context::memory::store(context, [offset.as_basic_value_enum(), value])?;
+    }
+
+    Ok(result.into_int_value())
+}
@@ -0,0 +1,106 @@
+//!
+//! The transient storage emulation.
+//!
+//! The zkEVM has no transient storage, so the EIP-1153 `tload` and `tstore` instructions are
+//! emulated with the contract storage. Each transient slot is mapped to the storage slot
+//! `keccak256(origin . nonce . key)`, where `origin` is `tx.origin` and `nonce` is its nonce
+//! holder minimal nonce. The pair is unique to the transaction, as the nonce is incremented
+//! before the transaction is executed, so the slots written by the previous transactions are
+//! never read again, and the storage behaves as if it is cleared at the end of the transaction.
+//!
+//! The preimage is three words long, so the slots cannot collide with the Solidity mapping and
+//! dynamic array slots, whose preimages are two and one word long. Unlike in EVM, the slots are
+//! not actually cleared, so they are charged as the persistent storage writes.
+//!
+
+use inkwell::values::BasicValue;
+
+use crate::environment::system_context_value::SystemContextValue;
+use crate::environment::system_contract;
+
+///
+/// Translates the `tload` instruction.
+///
+pub fn load<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    key: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let slot = slot(context, key)?;
+    compiler_llvm_context::storage::load(context, [slot.as_basic_value_enum()])
+}
+
+///
+/// Translates the `tstore` instruction.
+///
+pub fn store<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    key: inkwell::values::IntValue<'ctx>,
+    value: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let slot = slot(context, key)?;
+    compiler_llvm_context::storage::store(
+        context,
+        [slot.as_basic_value_enum(), value.as_basic_value_enum()],
+    )
+}
+
+///
+/// Returns the storage slot of the transient `key` in the current transaction.
+///
+/// The preimage is written to the beginning of the heap, whose words are saved before hashing
+/// and restored after it, like in the system contract calls.
+///
+fn slot<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    key: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<inkwell::values::IntValue<'ctx>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let origin = system_contract::static_call(
+        context,
+        system_contract::ADDRESS_SYSTEM_CONTEXT,
+        SystemContextValue::Origin.selector(),
+        &[],
+    )?;
+    let nonce = system_contract::static_call(
+        context,
+        system_contract::ADDRESS_NONCE_HOLDER,
+        system_contract::SELECTOR_GET_MIN_NONCE,
+        &[origin],
+    )?;
+    let preimage = [origin, nonce, key];
+
+    let mut saved = Vec::with_capacity(preimage.len());
+    for (index, value) in preimage.into_iter().enumerate() {
+        let offset = context.field_const((index * compiler_common::SIZE_FIELD) as u64);
+        let saved_value =
+            compiler_llvm_context::memory::load(context, [offset.as_basic_value_enum()])?
+                .expect("Always exists");
+        saved.push((offset, saved_value));
+        compiler_llvm_context::memory::store(
+            context,
+            [offset.as_basic_value_enum(), value.as_basic_value_enum()],
+        )?;
+    }
+
+    let slot = compiler_llvm_context::hash::keccak256(
+        context,
+        context.field_const(0),
+        context.field_const((saved.len() * compiler_common::SIZE_FIELD) as u64),
+    )?
+    .expect("Always exists")
+    .into_int_value();
+
+    for (offset, value) in saved.into_iter() {
+        compiler_llvm_context::memory::store(context, [offset.as_basic_value_enum(), value])?;
+    }
+
+    Ok(slot)
+}
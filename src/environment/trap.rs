@@ -0,0 +1,89 @@
+//!
+//! The stubbed operation trap.
+//!
+
+use inkwell::values::BasicValue;
+
+use crate::environment::system_contract::SELECTOR_SIZE;
+
+/// The `Error(string)` function selector, which solc uses for the revert reasons.
+pub const SELECTOR_ERROR: &str = "08c379a0";
+
+/// The revert reason prefix, followed by the operation name.
+pub const REASON_PREFIX: &str = "zkEVM unsupported operation: ";
+
+///
+/// Reverts with the `Error(string)` payload, whose reason identifies the `operation`.
+///
+/// The payload is written to the beginning of the heap, which is not restored, as the execution
+/// never returns. The code following the trap is translated into a new block without
+/// predecessors, so the translators can build the stubbed value as usual.
+///
+pub fn trap<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    operation: &str,
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let reason = format!("{}{}", REASON_PREFIX, operation);
+    let reason_offset = SELECTOR_SIZE + 2 * compiler_common::SIZE_FIELD;
+
+    let mut selector = SELECTOR_ERROR.to_owned();
+    selector.push_str(
+        "00".repeat(compiler_common::SIZE_FIELD - SELECTOR_SIZE)
+            .as_str(),
+    );
+    let mut words = vec![
+        (0, context.field_const_str_hex(selector.as_str())),
+        (
+            SELECTOR_SIZE,
+            context.field_const(compiler_common::SIZE_FIELD as u64),
+        ),
+        (
+            SELECTOR_SIZE + compiler_common::SIZE_FIELD,
+            context.field_const(reason.len() as u64),
+        ),
+    ];
+    for (index, chunk) in reason
+        .as_bytes()
+        .chunks(compiler_common::SIZE_FIELD)
+        .enumerate()
+    {
+        let mut chunk = hex::encode(chunk);
+        chunk.push_str(
+            "0".repeat(compiler_common::SIZE_FIELD * 2 - chunk.len())
+                .as_str(),
+        );
+        words.push((
+            reason_offset + index * compiler_common::SIZE_FIELD,
+            context.field_const_str_hex(chunk.as_str()),
+        ));
+    }
+
+    let size = words
+        .last()
+        .map(|(offset, _value)| offset + compiler_common::SIZE_FIELD)
+        .expect("Always exists");
+    for (offset, value) in words.into_iter() {
+        compiler_llvm_context::memory::store(
+            context,
+            [
+                context.field_const(offset as u64).as_basic_value_enum(),
+                value.as_basic_value_enum(),
+            ],
+        )?;
+    }
+    compiler_llvm_context::r#return::revert(
+        context,
+        [
+            context.field_const(0).as_basic_value_enum(),
+            context.field_const(size as u64).as_basic_value_enum(),
+        ],
+    )?;
+
+    let unreachable_block = context.append_basic_block("trap_unreachable");
+    context.set_basic_block(unreachable_block);
+
+    Ok(())
+}
@@ -2,6 +2,11 @@
 //! Translates the jump operations.
 //!
 
+use inkwell::types::BasicType;
+use inkwell::values::BasicValue;
+
+use crate::evm::ethereal_ir::function::block::element::call::Call;
+
 ///
 /// Translates the unconditional jump.
 ///
@@ -67,3 +72,143 @@ where
 
     Ok(None)
 }
+
+///
+/// Translates the recovered function call.
+///
+/// The arguments are loaded from the stack slots above the return address, which is found at
+/// the `height` slot. The return values are stored starting from the same slot, and the code
+/// continues at the return address block, unless the callee never returns.
+///
+pub fn call<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    call: Call,
+    height: usize,
+    stack_hash: md5::Digest,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let function = context
+        .functions
+        .get(call.name.as_str())
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Undeclared function `{}`", call.name))?;
+
+    let mut arguments = Vec::with_capacity(call.input_size + 1);
+    for index in 0..call.input_size {
+        let pointer = context.evm().stack[height + 1 + index]
+            .to_llvm()
+            .into_pointer_value();
+        let argument = context.build_load(pointer, format!("argument_{}", index).as_str());
+        arguments.push(argument);
+    }
+    if let Some(compiler_llvm_context::FunctionReturn::Compound { size, .. }) = function.r#return {
+        let r#type = context.structure_type(vec![context.field_type().as_basic_type_enum(); size]);
+        let pointer = context.build_alloca(
+            r#type,
+            format!("{}_return_pointer_argument", call.name).as_str(),
+        );
+        context.build_store(pointer, r#type.const_zero());
+        arguments.insert(0, pointer.as_basic_value_enum());
+    }
+
+    let return_value = context.build_invoke(
+        function.value,
+        arguments.as_slice(),
+        format!("{}_return_value", call.name).as_str(),
+    );
+
+    let output_size = match call.output_size {
+        Some(output_size) => output_size,
+        None => {
+            context.build_unreachable();
+            return Ok(None);
+        }
+    };
+    match function.r#return {
+        Some(compiler_llvm_context::FunctionReturn::Compound { .. }) => {
+            let return_pointer = return_value.expect("Always exists").into_pointer_value();
+            for index in 0..output_size {
+                let value_pointer = unsafe {
+                    context.builder().build_gep(
+                        return_pointer,
+                        &[
+                            context.field_const(0),
+                            context
+                                .integer_type(compiler_common::BITLENGTH_X32)
+                                .const_int(index as u64, false),
+                        ],
+                        format!("{}_return_value_{}_gep_pointer", call.name, index).as_str(),
+                    )
+                };
+                let value = context.build_load(
+                    value_pointer,
+                    format!("{}_return_value_{}", call.name, index).as_str(),
+                );
+                let pointer = context.evm().stack[height + index]
+                    .to_llvm()
+                    .into_pointer_value();
+                context.build_store(pointer, value);
+            }
+        }
+        _ => {
+            if let Some(value) = return_value {
+                let pointer = context.evm().stack[height].to_llvm().into_pointer_value();
+                context.build_store(pointer, value);
+            }
+        }
+    }
+
+    let block = context
+        .function()
+        .evm()
+        .find_block(&call.return_key, &stack_hash)?;
+    context.build_unconditional_branch(block.inner);
+
+    Ok(None)
+}
+
+///
+/// Translates the recovered function return.
+///
+/// The return values are found in the lowest stack slots, below the return address.
+///
+pub fn r#return<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    match context.function().r#return {
+        Some(compiler_llvm_context::FunctionReturn::Primitive { pointer }) => {
+            let value_pointer = context.evm().stack[0].to_llvm().into_pointer_value();
+            let value = context.build_load(value_pointer, "return_value");
+            context.build_store(pointer, value);
+        }
+        Some(compiler_llvm_context::FunctionReturn::Compound { pointer, size }) => {
+            for index in 0..size {
+                let value_pointer = context.evm().stack[index].to_llvm().into_pointer_value();
+                let value =
+                    context.build_load(value_pointer, format!("return_{}_value", index).as_str());
+                let pointer = unsafe {
+                    context.builder().build_gep(
+                        pointer,
+                        &[
+                            context.field_const(0),
+                            context
+                                .integer_type(compiler_common::BITLENGTH_X32)
+                                .const_int(index as u64, false),
+                        ],
+                        format!("return_{}_gep_pointer", index).as_str(),
+                    )
+                };
+                context.build_store(pointer, value);
+            }
+        }
+        _ => {}
+    }
+    context.build_unconditional_branch(context.function().return_block);
+
+    Ok(None)
+}
@@ -11,6 +11,8 @@ use std::collections::HashMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::yul::lexer::token::source_location::SourceLocation;
+
 use self::name::Name;
 
 ///
@@ -19,9 +21,27 @@ use self::name::Name;
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Instruction {
     /// The opcode or tag identifier.
+    #[serde(
+        deserialize_with = "Name::deserialize_or_unknown",
+        serialize_with = "Name::serialize_or_unknown"
+    )]
     pub name: Name,
     /// The optional value argument.
     pub value: Option<String>,
+
+    /// The Solidity source code index, or `-1` if unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<isize>,
+    /// The Solidity source code start byte offset, or `-1` if unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub begin: Option<isize>,
+    /// The Solidity source code end byte offset, or `-1` if unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<isize>,
+
+    /// Whether the stubbed instruction is compiled to a revert, as set in the trap mode.
+    #[serde(skip)]
+    pub is_trapped: bool,
 }
 
 impl Instruction {
@@ -69,9 +89,12 @@ impl Instruction {
             Name::MLOAD => 1,
             Name::MSTORE => 2,
             Name::MSTORE8 => 2,
+            Name::MCOPY => 3,
 
             Name::SLOAD => 1,
             Name::SSTORE => 2,
+            Name::TLOAD => 1,
+            Name::TSTORE => 2,
             Name::PUSHIMMUTABLE => 0,
             Name::ASSIGNIMMUTABLE => {
                 if version.minor >= 8 {
@@ -106,6 +129,8 @@ impl Instruction {
             Name::CREATE2 => 4,
 
             Name::BLOCKHASH => 1,
+            Name::BALANCE => 1,
+            Name::BLOBHASH => 1,
 
             Name::EXTCODECOPY => 4,
             Name::EXTCODEHASH => 1,
@@ -127,6 +152,7 @@ impl Instruction {
             Name::PUSHLIB => 1,
             Name::PUSHDEPLOYADDRESS => 1,
 
+            Name::PUSH0 => 1,
             Name::PUSH1 => 1,
             Name::PUSH2 => 1,
             Name::PUSH3 => 1,
@@ -211,6 +237,7 @@ impl Instruction {
             Name::MLOAD => 1,
 
             Name::SLOAD => 1,
+            Name::TLOAD => 1,
             Name::PUSHIMMUTABLE => 1,
 
             Name::CALLDATALOAD => 1,
@@ -244,8 +271,12 @@ impl Instruction {
             Name::BLOCKHASH => 1,
             Name::COINBASE => 1,
             Name::DIFFICULTY => 1,
+            Name::PREVRANDAO => 1,
             Name::GASLIMIT => 1,
+            Name::GASPRICE => 1,
             Name::BASEFEE => 1,
+            Name::BLOBHASH => 1,
+            Name::BLOBBASEFEE => 1,
             Name::EXTCODEHASH => 1,
 
             _ => 0,
@@ -263,6 +294,7 @@ impl Instruction {
             if let Instruction {
                 name: Name::PUSH_ContractHash | Name::PUSH_ContractHashSize | Name::PUSH_Data,
                 value: Some(value),
+                ..
             } = instruction
             {
                 *value = mapping
@@ -279,9 +311,53 @@ impl Instruction {
     /// Initializes an INVALID instruction to terminate an invalid unreachable block part.
     ///
     pub fn invalid() -> Self {
-        Self {
-            name: Name::INVALID,
-            value: None,
+        Self::from(Name::INVALID)
+    }
+
+    ///
+    /// Checks whether the instruction has no zkEVM equivalent, so it is compiled to a constant or
+    /// a no-op.
+    ///
+    /// The data pushes longer than a field are also compiled to zero constants.
+    ///
+    pub fn is_stubbed(&self) -> bool {
+        match self.name {
+            Name::PC | Name::MSIZE | Name::EXTCODECOPY | Name::SELFDESTRUCT | Name::BLOBBASEFEE => {
+                true
+            }
+            Name::PUSH_Data => self
+                .value
+                .as_ref()
+                .map(|value| value.len() > compiler_common::SIZE_FIELD * 2)
+                .unwrap_or_default(),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks whether the instruction reads the code of another account, which differs between
+    /// EVM and zkEVM.
+    ///
+    pub fn is_ext_code(&self) -> bool {
+        matches!(
+            self.name,
+            Name::EXTCODESIZE | Name::EXTCODEHASH | Name::EXTCODECOPY
+        )
+    }
+
+    ///
+    /// Returns the Solidity source code location of the instruction, if it is known.
+    ///
+    pub fn source_location(&self) -> Option<SourceLocation> {
+        match (self.source, self.begin, self.end) {
+            (Some(source), Some(begin), Some(end)) if source >= 0 && begin >= 0 && end >= begin => {
+                Some(SourceLocation::new(
+                    source as usize,
+                    begin as usize,
+                    (end - begin) as usize,
+                ))
+            }
+            _ => None,
         }
     }
 }
@@ -2,15 +2,20 @@
 //! The EVM instruction name.
 //!
 
+use serde::de::value::StrDeserializer;
+use serde::de::IntoDeserializer;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
 
 use crate::evm::assembly::instruction::Instruction;
+use crate::evm_version::EVMVersion;
 
 ///
 /// The EVM instruction name.
 ///
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum Name {
@@ -29,6 +34,8 @@ pub enum Name {
     #[serde(rename = "PUSH [$]")]
     PUSH_ContractHash,
 
+    /// The eponymous EVM instruction.
+    PUSH0,
     /// The eponymous EVM instruction.
     PUSH1,
     /// The eponymous EVM instruction.
@@ -237,12 +244,18 @@ pub enum Name {
     MSTORE,
     /// The eponymous EVM instruction.
     MSTORE8,
+    /// The eponymous EVM instruction.
+    MCOPY,
 
     /// The eponymous EVM instruction.
     SLOAD,
     /// The eponymous EVM instruction.
     SSTORE,
     /// The eponymous EVM instruction.
+    TLOAD,
+    /// The eponymous EVM instruction.
+    TSTORE,
+    /// The eponymous EVM instruction.
     PUSHIMMUTABLE,
     /// The eponymous EVM instruction.
     ASSIGNIMMUTABLE,
@@ -340,18 +353,81 @@ pub enum Name {
     /// The eponymous EVM instruction.
     DIFFICULTY,
     /// The eponymous EVM instruction.
+    PREVRANDAO,
+    /// The eponymous EVM instruction.
     GASLIMIT,
     /// The eponymous EVM instruction.
+    GASPRICE,
+    /// The eponymous EVM instruction.
     EXTCODECOPY,
     /// The eponymous EVM instruction.
     EXTCODEHASH,
     /// The eponymous EVM instruction.
     BASEFEE,
+    /// The eponymous EVM instruction.
+    BLOBHASH,
+    /// The eponymous EVM instruction.
+    BLOBBASEFEE,
+
+    /// The instruction unknown to the compiler, which is rejected before the translation.
+    #[serde(skip)]
+    Unknown(String),
+}
+
+impl Name {
+    ///
+    /// Returns the EVM version introducing the instruction, if it is newer than the oldest
+    /// supported one.
+    ///
+    pub fn evm_version(&self) -> Option<EVMVersion> {
+        match self {
+            Self::PREVRANDAO => Some(EVMVersion::Paris),
+            Self::PUSH0 => Some(EVMVersion::Shanghai),
+            Self::TLOAD | Self::TSTORE | Self::MCOPY | Self::BLOBHASH | Self::BLOBBASEFEE => {
+                Some(EVMVersion::Cancun)
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Deserializes the instruction name, keeping the unknown ones instead of failing, so they
+    /// can be reported with the contract they are found in.
+    ///
+    pub fn deserialize_or_unknown<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        let deserializer: StrDeserializer<serde::de::value::Error> =
+            name.as_str().into_deserializer();
+        Ok(Self::deserialize(deserializer).unwrap_or(Self::Unknown(name)))
+    }
+
+    ///
+    /// Serializes the instruction name, including the unknown ones.
+    ///
+    pub fn serialize_or_unknown<S>(name: &Self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match name {
+            Self::Unknown(name) => serializer.serialize_str(name.as_str()),
+            name => name.serialize(serializer),
+        }
+    }
 }
 
 impl From<Name> for Instruction {
     fn from(name: Name) -> Self {
-        Self { name, value: None }
+        Self {
+            name,
+            value: None,
+            source: None,
+            begin: None,
+            end: None,
+            is_trapped: false,
+        }
     }
 }
 
@@ -359,6 +435,7 @@ impl std::fmt::Display for Name {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Tag => write!(f, "Tag ",),
+            Self::Unknown(name) => write!(f, "{:16}", name),
             _ => write!(
                 f,
                 "{:16}",
@@ -13,6 +13,7 @@ use serde::Serialize;
 
 use crate::evm::ethereal_ir::entry_link::EntryLink;
 use crate::evm::ethereal_ir::EtherealIR;
+use crate::evm_version::EVMVersion;
 
 use self::data::Data;
 use self::instruction::name::Name as InstructionName;
@@ -164,6 +165,92 @@ impl Assembly {
 
         Ok(index_path_mapping)
     }
+
+    ///
+    /// Returns the constructor and selector instructions compiled to stubs, with the code part
+    /// names and the instruction indexes, as printed by the EVM dump.
+    ///
+    pub fn stubbed_instructions(&self) -> Vec<(&'static str, usize, &Instruction)> {
+        self.code_parts()
+            .into_iter()
+            .flat_map(|(part, code)| {
+                code.iter()
+                    .enumerate()
+                    .filter(|(_index, instruction)| instruction.is_stubbed())
+                    .map(move |(index, instruction)| (part, index, instruction))
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the constructor and selector instructions unknown to the compiler, or introduced
+    /// by an EVM version newer than the target one.
+    ///
+    pub fn unsupported_instructions(&self, evm_version: EVMVersion) -> Vec<&Instruction> {
+        self.code_parts()
+            .into_iter()
+            .flat_map(|(_part, code)| code.iter())
+            .filter(|instruction| match instruction.name {
+                InstructionName::Unknown(_) => true,
+                ref name => name
+                    .evm_version()
+                    .map(|required| evm_version < required)
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    ///
+    /// Checks whether the constructor or selector instructions read the code of other accounts.
+    ///
+    pub fn uses_ext_code(&self) -> bool {
+        self.code_parts()
+            .into_iter()
+            .flat_map(|(_part, code)| code.iter())
+            .any(Instruction::is_ext_code)
+    }
+
+    ///
+    /// Marks the constructor and selector instructions compiled to stubs, so they are compiled
+    /// to reverts identifying the instructions instead.
+    ///
+    pub fn trap_stubbed_instructions(&mut self) {
+        let selector_code = self
+            .data
+            .as_mut()
+            .and_then(|data| data.get_mut("0"))
+            .and_then(|data| data.get_assembly_mut())
+            .and_then(|assembly| assembly.code.as_mut());
+
+        for instruction in self
+            .code
+            .iter_mut()
+            .chain(selector_code)
+            .flat_map(|code| code.iter_mut())
+        {
+            instruction.is_trapped = instruction.is_stubbed();
+        }
+    }
+
+    ///
+    /// Returns the constructor and selector instructions with the code part names.
+    ///
+    fn code_parts(&self) -> Vec<(&'static str, &[Instruction])> {
+        let selector_code = self
+            .data
+            .as_ref()
+            .and_then(|data| data.get("0"))
+            .and_then(|data| data.get_assembly())
+            .and_then(|assembly| assembly.code.as_deref());
+
+        [
+            ("constructor", self.code.as_deref()),
+            ("selector", selector_code),
+        ]
+        .into_iter()
+        .filter_map(|(part, code)| code.map(|code| (part, code)))
+        .collect()
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Assembly
@@ -0,0 +1,40 @@
+//!
+//! The Ethereal IR recovered function call.
+//!
+
+///
+/// The Ethereal IR recovered function call.
+///
+/// Attached to the `JUMP [in]` instructions whose callee has been recovered as a separate
+/// function, instead of being inlined.
+///
+#[derive(Debug, Clone)]
+pub struct Call {
+    /// The callee function name.
+    pub name: String,
+    /// The number of the callee arguments, found above the return address.
+    pub input_size: usize,
+    /// The number of the callee return values, or `None` if the callee never returns.
+    pub output_size: Option<usize>,
+    /// The block the callee returns to.
+    pub return_key: compiler_llvm_context::FunctionBlockKey,
+}
+
+impl Call {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        input_size: usize,
+        output_size: Option<usize>,
+        return_key: compiler_llvm_context::FunctionBlockKey,
+    ) -> Self {
+        Self {
+            name,
+            input_size,
+            output_size,
+            return_key,
+        }
+    }
+}
@@ -2,14 +2,18 @@
 //! The Ethereal IR block element.
 //!
 
+pub mod call;
 pub mod stack;
 
 use inkwell::values::BasicValue;
+use num::ToPrimitive;
 
 use crate::evm::assembly::instruction::name::Name as InstructionName;
 use crate::evm::assembly::instruction::Instruction;
 use crate::evm::ethereal_ir::EtherealIR;
 
+use self::call::Call;
+use self::stack::element::Element as StackElement;
 use self::stack::Stack;
 
 ///
@@ -23,6 +27,8 @@ pub struct Element {
     pub instruction: Instruction,
     /// The stack data.
     pub stack: Stack,
+    /// The recovered function call, if the element is a `JUMP [in]` to it.
+    pub call: Option<Call>,
 }
 
 impl Element {
@@ -34,6 +40,7 @@ impl Element {
             solc_version,
             instruction,
             stack: Stack::new(),
+            call: None,
         }
     }
 
@@ -61,24 +68,20 @@ impl Element {
     }
 
     ///
-    /// Pops the specified number of arguments.
+    /// Returns the error on the `CODECOPY` operand `description` of the contract at `path`, which
+    /// is not modeled by the zkEVM code layout.
     ///
-    fn pop_arguments<'ctx, D>(
-        &mut self,
-        context: &mut compiler_llvm_context::Context<'ctx, D>,
-    ) -> Vec<compiler_llvm_context::Argument<'ctx>>
-    where
-        D: compiler_llvm_context::Dependency,
-    {
-        let input_size = self.instruction.input_size(&context.evm().version);
-        let mut arguments = Vec::with_capacity(input_size);
-        for index in 0..input_size {
-            let argument = context.evm().stack
-                [self.stack.elements.len() - self.instruction.output_size() - index - 1]
-                .to_owned();
-            arguments.push(argument);
-        }
-        arguments
+    fn code_copy_error(&self, path: &str, description: &str) -> anyhow::Error {
+        let location = match self.instruction.source_location() {
+            Some(location) => format!(", at the Solidity source location `{}`", location),
+            None => String::new(),
+        };
+        anyhow::anyhow!(
+            "The `CODECOPY` {} in the contract `{}` is not modeled by the zkEVM code layout{}",
+            description,
+            path,
+            location
+        )
     }
 }
 
@@ -94,6 +97,7 @@ where
         let mut original = self.instruction.value.clone();
 
         let value = match self.instruction.name {
+            InstructionName::PUSH0 => Ok(Some(context.field_const(0).as_basic_value_enum())),
             InstructionName::PUSH
             | InstructionName::PUSH1
             | InstructionName::PUSH2
@@ -171,6 +175,9 @@ where
                     .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?;
 
                 if value.len() > compiler_common::SIZE_FIELD * 2 {
+                    if self.instruction.is_trapped {
+                        crate::environment::trap::trap(context, "PUSH data")?;
+                    }
                     Ok(Some(context.field_const(0).as_basic_value_enum()))
                 } else {
                     crate::evm::assembly::instruction::stack::push(context, value)
@@ -375,6 +382,30 @@ where
                     self.stack.hash(),
                 )
             }
+            InstructionName::JUMP if self.call.is_some() => {
+                let call = self.call.take().expect("Always exists");
+                self.stack.pop_tag()?;
+                let height = self.stack.elements.len() - call.input_size - 1;
+                self.stack.elements.truncate(height);
+                for _ in 0..call.output_size.unwrap_or_default() {
+                    self.stack.push(StackElement::Value);
+                }
+
+                crate::evm::assembly::instruction::jump::call(
+                    context,
+                    call,
+                    height,
+                    self.stack.hash(),
+                )
+            }
+            InstructionName::JUMP
+                if matches!(
+                    self.stack.elements.last(),
+                    Some(StackElement::ReturnAddress)
+                ) =>
+            {
+                crate::evm::assembly::instruction::jump::r#return(context)
+            }
             InstructionName::JUMP => {
                 let destination = self.stack.pop_tag()?;
 
@@ -647,6 +678,15 @@ where
                     .expect("Always valid");
                 compiler_llvm_context::memory::store_byte(context, arguments)
             }
+            InstructionName::MCOPY => {
+                let arguments = self.pop_arguments_llvm(context);
+                crate::environment::memory_copy(
+                    context,
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                )
+            }
 
             InstructionName::SLOAD => {
                 let arguments = self
@@ -662,6 +702,18 @@ where
                     .expect("Always valid");
                 compiler_llvm_context::storage::store(context, arguments)
             }
+            InstructionName::TLOAD => {
+                let arguments = self.pop_arguments_llvm(context);
+                crate::environment::transient_storage::load(context, arguments[0].into_int_value())
+            }
+            InstructionName::TSTORE => {
+                let arguments = self.pop_arguments_llvm(context);
+                crate::environment::transient_storage::store(
+                    context,
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                )
+            }
             InstructionName::PUSHIMMUTABLE => {
                 let key = self
                     .instruction
@@ -694,106 +746,96 @@ where
                     .expect("Always valid");
                 compiler_llvm_context::calldata::copy(context, arguments)
             }
-            InstructionName::CODESIZE => compiler_llvm_context::calldata::size(context),
+            InstructionName::CODESIZE => crate::environment::code_size(context),
             InstructionName::CODECOPY => {
-                let mut arguments =
-                    Vec::with_capacity(self.instruction.input_size(&self.solc_version));
-                let arguments_with_original: [compiler_llvm_context::Argument<'ctx>; 3] = self
-                    .pop_arguments(context)
-                    .try_into()
-                    .expect("Always valid");
-                for (index, argument) in arguments_with_original.iter().enumerate() {
-                    let pointer = argument.value.into_pointer_value();
-                    let value = context.build_load(pointer, format!("argument_{}", index).as_str());
-                    arguments.push(value);
-                }
-
-                let parent = context.module().get_name().to_str().expect("Always valid");
-
-                let original_destination = arguments_with_original[0].original.as_deref();
-                let original_source = arguments_with_original[1].original.as_deref();
+                let length = self.stack.elements.len();
+                let destination = self.stack.elements[length - 1].to_owned();
+                let source = self.stack.elements[length - 2].to_owned();
+                let size = self.stack.elements[length - 3].to_owned();
+                let arguments = self.pop_arguments_llvm(context);
 
-                match original_source {
-                    Some(source)
-                        if !source.chars().all(|char| char.is_ascii_hexdigit())
-                            && source != parent =>
-                    {
-                        compiler_llvm_context::memory::store(context, [arguments[0], arguments[1]])
-                    }
-                    Some(source)
-                        if !source.chars().all(|char| char.is_ascii_hexdigit())
-                            && source == parent =>
-                    {
-                        match original_destination {
-                            Some(length) if length == "B" => {
-                                if let Some(compiler_llvm_context::CodeType::Deploy) =
-                                    context.code_type
-                                {
-                                    let address = compiler_llvm_context::contract_context::get(
-                                        context,
-                                        compiler_common::ContextValue::Address,
-                                    )?
-                                    .expect("Always exists");
-                                    compiler_llvm_context::immutable::store(
-                                        context,
-                                        EtherealIR::DEPLOY_ADDRESS_STORAGE_KEY.to_owned(),
-                                        address.into_int_value(),
-                                    )?;
-                                }
+                let parent = context
+                    .module()
+                    .get_name()
+                    .to_str()
+                    .expect("Always valid")
+                    .to_owned();
 
-                                compiler_llvm_context::memory::store_byte(
-                                    context,
-                                    [
-                                        context.field_const_str_hex(length).as_basic_value_enum(),
-                                        context.field_const_str_hex("73").as_basic_value_enum(),
-                                    ],
-                                )
+                match (source, context.code_type) {
+                    // The data section, which is copied as a constant.
+                    (StackElement::Data(data), _) => {
+                        let data = hex::decode(data.as_str())?;
+                        let size = match size {
+                            StackElement::Constant(size) => size
+                                .to_usize()
+                                .filter(|size| *size <= data.len())
+                                .ok_or_else(|| {
+                                    self.code_copy_error(
+                                        parent.as_str(),
+                                        format!("size {} of the data section", size).as_str(),
+                                    )
+                                })?,
+                            size => {
+                                return Err(self.code_copy_error(
+                                    parent.as_str(),
+                                    format!("size `{}` of the data section", size).as_str(),
+                                ))
                             }
-                            _ => Ok(None),
-                        }
+                        };
+                        crate::environment::data_copy(
+                            context,
+                            arguments[0].into_int_value(),
+                            &data[..size],
+                        )?;
+                        Ok(None)
+                    }
+                    // The dependency code, whose hash is written instead.
+                    (StackElement::Path(path), _) if path != parent => {
+                        compiler_llvm_context::memory::store(context, [arguments[0], arguments[1]])
                     }
-                    Some(source) if source.chars().all(|char| char.is_ascii_hexdigit()) => {
-                        let mut offset = 0;
-                        for (index, chunk) in source
-                            .chars()
-                            .collect::<Vec<char>>()
-                            .chunks(compiler_common::SIZE_FIELD * 2)
-                            .enumerate()
-                        {
-                            let mut value_string = chunk.iter().collect::<String>();
-                            value_string.push_str(
-                                "0".repeat((compiler_common::SIZE_FIELD * 2) - chunk.len())
-                                    .as_str(),
-                            );
+                    // The own runtime code, which is only returned by the deploy code, whereas the
+                    // zkEVM deploys it by the hash, so only the library address marker is written.
+                    (StackElement::Path(_), Some(compiler_llvm_context::CodeType::Deploy)) => {
+                        if destination == StackElement::Constant(num::BigUint::from(0x0Bu64)) {
+                            let address = compiler_llvm_context::contract_context::get(
+                                context,
+                                compiler_common::ContextValue::Address,
+                            )?
+                            .expect("Always exists");
+                            compiler_llvm_context::immutable::store(
+                                context,
+                                EtherealIR::DEPLOY_ADDRESS_STORAGE_KEY.to_owned(),
+                                address.into_int_value(),
+                            )?;
 
-                            let datacopy_destination = context.builder().build_int_add(
-                                arguments[0].into_int_value(),
-                                context.field_const(offset as u64),
-                                format!("datacopy_destination_index_{}", index).as_str(),
-                            );
-                            let datacopy_value = context.field_const_str(value_string.as_str());
-                            compiler_llvm_context::memory::store(
+                            compiler_llvm_context::memory::store_byte(
                                 context,
                                 [
-                                    datacopy_destination.as_basic_value_enum(),
-                                    datacopy_value.as_basic_value_enum(),
+                                    arguments[0],
+                                    context.field_const_str_hex("73").as_basic_value_enum(),
                                 ],
                             )?;
-                            offset += chunk.len() / 2;
                         }
                         Ok(None)
                     }
-                    Some(_source) => Ok(None),
-                    None => compiler_llvm_context::calldata::copy(
+                    // The code at a computed offset, which is the constructor arguments.
+                    (
+                        StackElement::Constant(_) | StackElement::Value,
+                        Some(compiler_llvm_context::CodeType::Deploy),
+                    ) => crate::environment::code_copy(
                         context,
-                        arguments.try_into().expect("Always valid"),
+                        arguments[0].into_int_value(),
+                        arguments[1].into_int_value(),
+                        arguments[2].into_int_value(),
                     ),
+                    (source, _) => Err(self
+                        .code_copy_error(parent.as_str(), format!("source `{}`", source).as_str())),
                 }
             }
             InstructionName::PUSHSIZE => Ok(Some(context.field_const(0).as_basic_value_enum())),
             InstructionName::EXTCODESIZE => {
-                let _arguments = self.pop_arguments_llvm(context);
-                Ok(Some(context.field_const(0xffff).as_basic_value_enum()))
+                let arguments = self.pop_arguments_llvm(context);
+                crate::environment::ext_code_size(context, arguments[0].into_int_value())
             }
             InstructionName::RETURNDATASIZE => compiler_llvm_context::return_data::size(context),
             InstructionName::RETURNDATACOPY => {
@@ -1016,40 +1058,105 @@ where
                 compiler_common::ContextValue::GasLeft,
             ),
 
-            InstructionName::GASLIMIT => Ok(Some(
-                context.field_const(u32::MAX as u64).as_basic_value_enum(),
-            )),
-            InstructionName::BASEFEE => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            InstructionName::COINBASE => Ok(Some(context.field_const(0).as_basic_value_enum())),
+            InstructionName::GASLIMIT => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::GasLimit,
+            ),
+            InstructionName::GASPRICE => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::GasPrice,
+            ),
+            InstructionName::BASEFEE => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::BaseFee,
+            ),
+            InstructionName::BLOBHASH => {
+                let arguments = self.pop_arguments_llvm(context);
+                crate::environment::blob_hash(context, arguments[0].into_int_value())
+            }
+            InstructionName::BLOBBASEFEE => {
+                if self.instruction.is_trapped {
+                    crate::environment::trap::trap(context, "BLOBBASEFEE")?;
+                }
+                // The minimal blob base fee, as there is no blob fee market in the zkEVM.
+                Ok(Some(context.field_const(1).as_basic_value_enum()))
+            }
+            InstructionName::COINBASE => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::CoinBase,
+            ),
 
-            InstructionName::CALLVALUE => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            InstructionName::BALANCE => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            InstructionName::SELFBALANCE => Ok(Some(context.field_const(0).as_basic_value_enum())),
+            InstructionName::CALLVALUE => crate::environment::call_value(context),
+            InstructionName::BALANCE => {
+                let arguments = self.pop_arguments_llvm(context);
+                crate::environment::balance(context, arguments[0].into_int_value())
+            }
+            InstructionName::SELFBALANCE => crate::environment::self_balance(context),
 
-            InstructionName::ORIGIN => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            InstructionName::CHAINID => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            InstructionName::BLOCKHASH => Ok(Some(context.field_const(0).as_basic_value_enum())),
+            InstructionName::ORIGIN => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::Origin,
+            ),
+            InstructionName::CHAINID => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::ChainId,
+            ),
+            InstructionName::BLOCKHASH => {
+                let arguments = self.pop_arguments_llvm(context);
+                crate::environment::block_hash(context, arguments[0].into_int_value())
+            }
 
-            InstructionName::MSIZE => Ok(Some(
-                context
-                    .field_const(((1 << 16) * compiler_common::SIZE_FIELD) as u64)
-                    .as_basic_value_enum(),
-            )),
-            InstructionName::DIFFICULTY => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            InstructionName::PC => Ok(Some(context.field_const(0).as_basic_value_enum())),
+            InstructionName::MSIZE => {
+                if self.instruction.is_trapped {
+                    crate::environment::trap::trap(context, "MSIZE")?;
+                }
+                Ok(Some(
+                    context
+                        .field_const(((1 << 16) * compiler_common::SIZE_FIELD) as u64)
+                        .as_basic_value_enum(),
+                ))
+            }
+            InstructionName::DIFFICULTY | InstructionName::PREVRANDAO => {
+                crate::environment::system_context(
+                    context,
+                    crate::environment::system_context_value::SystemContextValue::Difficulty,
+                )
+            }
+            InstructionName::PC => {
+                if self.instruction.is_trapped {
+                    crate::environment::trap::trap(context, "PC")?;
+                }
+                Ok(Some(context.field_const(0).as_basic_value_enum()))
+            }
 
             InstructionName::EXTCODECOPY => {
-                let _arguments = self.pop_arguments_llvm(context);
-                Ok(None)
+                let arguments = self.pop_arguments_llvm(context);
+                if self.instruction.is_trapped {
+                    crate::environment::trap::trap(context, "EXTCODECOPY")?;
+                    Ok(None)
+                } else {
+                    crate::environment::ext_code_copy(
+                        context,
+                        arguments[1].into_int_value(),
+                        arguments[3].into_int_value(),
+                    )
+                }
             }
             InstructionName::EXTCODEHASH => {
-                let _arguments = self.pop_arguments_llvm(context);
-                Ok(Some(context.field_const(0).as_basic_value_enum()))
+                let arguments = self.pop_arguments_llvm(context);
+                crate::environment::ext_code_hash(context, arguments[0].into_int_value())
             }
             InstructionName::SELFDESTRUCT => {
                 let _arguments = self.pop_arguments_llvm(context);
+                if self.instruction.is_trapped {
+                    crate::environment::trap::trap(context, "SELFDESTRUCT")?;
+                }
                 Ok(None)
             }
+
+            InstructionName::Unknown(ref name) => {
+                anyhow::bail!("Unsupported opcode `{}`", name)
+            }
         }?;
 
         if let Some(value) = value {
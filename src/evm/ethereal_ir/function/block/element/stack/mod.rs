@@ -19,6 +19,15 @@ impl Stack {
     /// The default stack size.
     pub const DEFAULT_STACK_SIZE: usize = 16;
 
+    /// The hash kind prefix of the non-tag values.
+    const HASH_KIND_VALUE: u8 = 0;
+
+    /// The hash kind prefix of the tags.
+    const HASH_KIND_TAG: u8 = 1;
+
+    /// The hash kind prefix of the return addresses.
+    const HASH_KIND_RETURN_ADDRESS: u8 = 2;
+
     ///
     /// A shortcut constructor.
     ///
@@ -39,18 +48,68 @@ impl Stack {
     /// The stack state hash, which acts as a block identifier.
     ///
     /// Each block clone has its own initial stack state, which uniquely identifies the block.
+    /// Only the stack depth, the tags, and the return addresses are hashed, as the other values
+    /// are merged within a clone. Each element is prefixed with its kind, and each tag with its
+    /// length, so the different states cannot be encoded into the same bytes.
     ///
     pub fn hash(&self) -> md5::Digest {
         let mut hash_context = md5::Context::new();
+        hash_context.consume((self.elements.len() as u64).to_be_bytes());
         for element in self.elements.iter() {
             match element {
-                Element::Tag(tag) => hash_context.consume(tag.to_bytes_be()),
-                _ => hash_context.consume([0]),
+                Element::Tag(tag) => {
+                    let bytes = tag.to_bytes_be();
+                    hash_context.consume([Self::HASH_KIND_TAG]);
+                    hash_context.consume((bytes.len() as u64).to_be_bytes());
+                    hash_context.consume(bytes);
+                }
+                Element::ReturnAddress => {
+                    hash_context.consume([Self::HASH_KIND_RETURN_ADDRESS]);
+                }
+                _ => hash_context.consume([Self::HASH_KIND_VALUE]),
             }
         }
         hash_context.compute()
     }
 
+    ///
+    /// Merges the `other` stack state with the same tags, replacing the differing elements with
+    /// unknown runtime values.
+    ///
+    /// Returns whether the stack state has changed, or an error if the stack depths or tags
+    /// differ, as such states belong to different block clones.
+    ///
+    pub fn merge(&mut self, other: &Self) -> anyhow::Result<bool> {
+        if self.elements.len() != other.elements.len() {
+            anyhow::bail!(
+                "Merging the stack states of different depths: {} and {}",
+                self,
+                other
+            );
+        }
+
+        let mut is_changed = false;
+        for (element, other_element) in self.elements.iter_mut().zip(other.elements.iter()) {
+            if element == other_element {
+                continue;
+            }
+            if matches!(element, Element::Tag(_) | Element::ReturnAddress)
+                || matches!(other_element, Element::Tag(_) | Element::ReturnAddress)
+            {
+                anyhow::bail!(
+                    "Merging the stack states with different tags: {} and {}",
+                    element,
+                    other_element
+                );
+            }
+            if !matches!(element, Element::Value) {
+                *element = Element::Value;
+                is_changed = true;
+            }
+        }
+        Ok(is_changed)
+    }
+
     ///
     /// Pushes a stack element.
     ///
@@ -120,3 +179,55 @@ impl std::fmt::Display for Stack {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::element::Element;
+    use super::Stack;
+
+    fn tag(value: u64) -> Element {
+        Element::Tag(num::BigUint::from(value))
+    }
+
+    #[test]
+    fn hash_distinguishes_tags_from_values() {
+        assert_ne!(
+            Stack::new_with_elements(vec![tag(0)]).hash(),
+            Stack::new_with_elements(vec![Element::Value]).hash()
+        );
+    }
+
+    #[test]
+    fn hash_distinguishes_tag_boundaries() {
+        assert_ne!(
+            Stack::new_with_elements(vec![tag(0x01), tag(0x0203)]).hash(),
+            Stack::new_with_elements(vec![tag(0x0102), tag(0x03)]).hash()
+        );
+    }
+
+    #[test]
+    fn merge_replaces_differing_values() {
+        let mut stack = Stack::new_with_elements(vec![tag(1), Element::Constant(2u64.into())]);
+        let other = Stack::new_with_elements(vec![tag(1), Element::Constant(3u64.into())]);
+        assert!(stack.merge(&other).expect("Always valid"));
+        assert_eq!(stack.elements, vec![tag(1), Element::Value]);
+        assert!(!stack.merge(&other).expect("Always valid"));
+    }
+
+    #[test]
+    fn merge_rejects_different_depths_and_tags() {
+        let mut stack = Stack::new_with_elements(vec![tag(1), Element::Value]);
+        assert!(stack
+            .merge(&Stack::new_with_elements(vec![tag(1)]))
+            .is_err());
+        assert!(stack
+            .merge(&Stack::new_with_elements(vec![tag(2), Element::Value]))
+            .is_err());
+        assert!(stack
+            .merge(&Stack::new_with_elements(vec![
+                Element::Value,
+                Element::Value
+            ]))
+            .is_err());
+    }
+}
@@ -3,11 +3,15 @@
 //!
 
 pub mod block;
+pub mod pending_call;
 pub mod queue_element;
+pub mod registry;
+pub mod r#type;
 pub mod visited_element;
 
 use compiler_llvm_context::FunctionBlockKey;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ops::BitAnd;
@@ -27,10 +31,14 @@ use crate::evm::ethereal_ir::function::block::element::stack::element::Element;
 use crate::evm::ethereal_ir::function::block::element::stack::Stack;
 use crate::evm::ethereal_ir::EtherealIR;
 
+use self::block::element::call::Call;
 use self::block::element::stack::element::Element as StackElement;
 use self::block::element::Element as BlockElement;
 use self::block::Block;
+use self::pending_call::PendingCall;
 use self::queue_element::QueueElement;
+use self::r#type::Type;
+use self::registry::Registry;
 use self::visited_element::VisitedElement;
 
 ///
@@ -40,29 +48,58 @@ use self::visited_element::VisitedElement;
 pub struct Function {
     /// The Solidity compiler version.
     pub solc_version: semver::Version,
+    /// The function name.
+    pub name: String,
+    /// The function type.
+    pub r#type: Type,
     /// The separately labelled blocks.
     pub blocks: BTreeMap<compiler_llvm_context::FunctionBlockKey, Vec<Block>>,
     /// The function stack size.
     pub stack_size: usize,
+    /// The names of the recovered functions called.
+    pub calls: BTreeSet<String>,
+    /// The calls to the functions being recovered, whose return values are not known yet.
+    pending_calls: Vec<PendingCall>,
 }
 
 impl Function {
+    /// The `JUMP` instruction value marking the internal function calls.
+    pub const JUMP_TYPE_IN: &'static str = "[in]";
+
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(
+    pub fn new(solc_version: semver::Version, name: String, r#type: Type) -> Self {
+        Self {
+            solc_version,
+            name,
+            r#type,
+            blocks: BTreeMap::new(),
+            stack_size: 0,
+            calls: BTreeSet::new(),
+            pending_calls: vec![],
+        }
+    }
+
+    ///
+    /// Assembles the initial function, starting from the deploy and runtime code entries.
+    ///
+    /// The internal functions called are recovered into the `registry` where possible, and are
+    /// inlined otherwise.
+    ///
+    pub fn try_initial(
         solc_version: semver::Version,
         blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
-        visited: &mut HashSet<VisitedElement>,
+        registry: &mut Registry,
     ) -> anyhow::Result<Self> {
-        let mut function = Self {
+        let mut function = Self::new(
             solc_version,
-            blocks: BTreeMap::new(),
-            stack_size: 0,
-        };
+            EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME.to_owned(),
+            Type::new_initial(),
+        );
         function.consume_block(
             blocks,
-            visited,
+            registry,
             QueueElement::new(
                 compiler_llvm_context::FunctionBlockKey::new(
                     compiler_llvm_context::CodeType::Deploy,
@@ -74,7 +111,7 @@ impl Function {
         )?;
         function.consume_block(
             blocks,
-            visited,
+            registry,
             QueueElement::new(
                 compiler_llvm_context::FunctionBlockKey::new(
                     compiler_llvm_context::CodeType::Runtime,
@@ -84,28 +121,77 @@ impl Function {
                 Stack::new(),
             ),
         )?;
+        function.consume_pending_calls(blocks, registry)?;
+        Ok(function.finalize())
+    }
+
+    ///
+    /// Assembles the internal function entered at `block_key` with `initial_stack`, which
+    /// consists of the return address followed by the arguments.
+    ///
+    /// Returns an error if the code does not behave as a function, that is, it uses the stack
+    /// below the return address, uses the return address as a value, or returns inconsistently.
+    ///
+    pub fn try_recovered(
+        solc_version: semver::Version,
+        name: String,
+        block_key: compiler_llvm_context::FunctionBlockKey,
+        initial_stack: Stack,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        registry: &mut Registry,
+    ) -> anyhow::Result<Self> {
+        let mut function = Self::new(
+            solc_version,
+            name,
+            Type::new_recovered(
+                block_key.clone(),
+                initial_stack.hash(),
+                initial_stack.elements.len() - 1,
+            ),
+        );
+        function.consume_block(
+            blocks,
+            registry,
+            QueueElement::new(block_key, None, initial_stack),
+        )?;
+        function.consume_pending_calls(blocks, registry)?;
         Ok(function.finalize())
     }
 
     ///
     /// Consumes the entry or a conditional block attached to another one.
     ///
+    /// The block is cloned for each state of the tags on the stack, as they are the jump
+    /// destinations. The stack states with the same tags share a clone, where the values that
+    /// differ are merged into unknown runtime values, and the clone is consumed again if its
+    /// initial stack state changes.
+    ///
     fn consume_block(
         &mut self,
         blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
-        visited: &mut HashSet<VisitedElement>,
+        registry: &mut Registry,
         mut queue_element: QueueElement,
     ) -> anyhow::Result<()> {
         let version = self.solc_version.to_owned();
 
         let mut queue = vec![];
 
-        let visited_element =
-            VisitedElement::new(queue_element.block_key.clone(), queue_element.stack.hash());
-        if visited.contains(&visited_element) {
-            return Ok(());
+        let predecessor = queue_element.predecessor.take();
+        let mut initial_stack = queue_element.stack.clone();
+        let mut predecessors = HashSet::with_capacity(Block::PREDECESSORS_HASHSET_DEFAULT_CAPACITY);
+        if let Some(clone) = self.find_block_mut(&queue_element.block_key, &initial_stack.hash()) {
+            if let Some(predecessor) = predecessor.clone() {
+                clone.insert_predecessor(predecessor);
+            }
+
+            let mut merged_stack = clone.initial_stack.clone();
+            if !merged_stack.merge(&initial_stack)? {
+                return Ok(());
+            }
+            initial_stack = merged_stack;
+            predecessors = clone.predecessors.clone();
         }
-        visited.insert(visited_element);
+        queue_element.stack = initial_stack.clone();
 
         let mut block = blocks
             .get(&queue_element.block_key)
@@ -113,10 +199,10 @@ impl Function {
             .ok_or_else(|| {
                 anyhow::anyhow!("Undeclared destination block {}", queue_element.block_key)
             })?;
-        block.initial_stack = queue_element.stack.clone();
-        let block = self.insert_block(block);
+        block.initial_stack = initial_stack;
         block.stack = block.initial_stack.clone();
-        if let Some(predecessor) = queue_element.predecessor.take() {
+        block.predecessors = predecessors;
+        if let Some(predecessor) = predecessor {
             block.insert_predecessor(predecessor);
         }
 
@@ -124,30 +210,332 @@ impl Function {
         for block_element in block.elements.iter_mut() {
             block_size += 1;
 
-            if Self::handle_instruction(
-                block.key.code_type,
+            if let Err(error) = self.handle_element(
+                blocks,
+                registry,
                 &mut block.stack,
                 block_element,
                 &version,
                 &mut queue,
                 &mut queue_element,
-            )
-            .is_err()
-            {
+            ) {
+                if let Type::Recovered { .. } = self.r#type {
+                    return Err(error);
+                }
+
                 block_element.stack = block.stack.clone();
                 block_element.instruction = Instruction::invalid();
                 break;
             }
         }
         block.elements.truncate(block_size);
+        self.insert_block(block);
 
         for element in queue.into_iter() {
-            self.consume_block(blocks, visited, element)?;
+            self.consume_block(blocks, registry, element)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Consumes the return blocks of the calls to the functions being recovered, as soon as
+    /// their numbers of return values are found.
+    ///
+    /// The recursive calls to the function itself are left never returning if its number of
+    /// return values is not found, as no other path returns. The unresolved calls to the other
+    /// functions being recovered fail, so the function is inlined into them instead.
+    ///
+    fn consume_pending_calls(
+        &mut self,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        registry: &mut Registry,
+    ) -> anyhow::Result<()> {
+        loop {
+            let mut is_progress = false;
+            for pending_call in std::mem::take(&mut self.pending_calls).into_iter() {
+                let output_size = match registry.output_size(pending_call.name.as_str()) {
+                    Some(output_size) => output_size,
+                    None => {
+                        self.pending_calls.push(pending_call);
+                        continue;
+                    }
+                };
+                is_progress = true;
+
+                if let Some(call) = self
+                    .find_block_mut(
+                        &pending_call.block.block_key,
+                        &pending_call.block.stack_hash,
+                    )
+                    .and_then(|block| block.elements.last_mut())
+                    .and_then(|element| element.call.as_mut())
+                {
+                    call.output_size = Some(output_size);
+                }
+
+                let mut stack = pending_call.stack;
+                for _ in 0..output_size {
+                    stack.push(StackElement::Value);
+                }
+                self.consume_block(
+                    blocks,
+                    registry,
+                    QueueElement::new(
+                        pending_call.return_key,
+                        Some(pending_call.block.block_key),
+                        stack,
+                    ),
+                )?;
+            }
+
+            if !is_progress {
+                break;
+            }
+        }
+
+        if let Some(pending_call) = self
+            .pending_calls
+            .iter()
+            .find(|pending_call| pending_call.name != self.name)
+        {
+            anyhow::bail!(
+                "The number of return values of function `{}` is unknown",
+                pending_call.name
+            );
+        }
+        self.pending_calls.clear();
+
+        Ok(())
+    }
+
+    ///
+    /// Processes an element, recovering the internal function calls and returns.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    fn handle_element(
+        &mut self,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        registry: &mut Registry,
+        block_stack: &mut Stack,
+        block_element: &mut BlockElement,
+        version: &semver::Version,
+        queue: &mut Vec<QueueElement>,
+        queue_element: &mut QueueElement,
+    ) -> anyhow::Result<()> {
+        let is_jump = matches!(block_element.instruction.name, InstructionName::JUMP);
+        let is_call =
+            is_jump && block_element.instruction.value.as_deref() == Some(Self::JUMP_TYPE_IN);
+        let is_return = is_jump
+            && matches!(
+                block_stack.elements.last(),
+                Some(StackElement::ReturnAddress)
+            );
+
+        if is_call
+            && self.handle_call(
+                blocks,
+                registry,
+                block_stack,
+                block_element,
+                queue_element,
+                queue,
+            )
+        {
+            return Ok(());
+        }
+        if is_return {
+            return self.handle_return(registry, block_stack, block_element);
+        }
+
+        if let Type::Recovered { .. } = self.r#type {
+            // The source original value, which is only known within the function pushing it,
+            // selects the code copy translation.
+            if let InstructionName::CODECOPY = block_element.instruction.name {
+                if let Some(StackElement::Value) = block_stack
+                    .elements
+                    .len()
+                    .checked_sub(2)
+                    .and_then(|index| block_stack.elements.get(index))
+                {
+                    anyhow::bail!("The code copy source is unknown");
+                }
+            }
+        }
+
+        let code_type = queue_element.block_key.code_type;
+        Self::handle_instruction(
+            code_type,
+            block_stack,
+            block_element,
+            version,
+            queue,
+            queue_element,
+        )?;
+
+        if let Type::Recovered { .. } = self.r#type {
+            let return_addresses = block_stack
+                .elements
+                .iter()
+                .filter(|element| matches!(element, StackElement::ReturnAddress))
+                .count();
+            if return_addresses != 1 {
+                anyhow::bail!("The return address is used as a value");
+            }
         }
 
         Ok(())
     }
 
+    ///
+    /// Recovers the callee of a `JUMP [in]` instruction as a separate function, returning
+    /// `false` if it must be inlined instead.
+    ///
+    /// The return address is looked for among the tags below the callee tag, starting from the
+    /// top, as the arguments may include function pointers. The callee is explored with its own
+    /// stack, where the tag arguments are kept to resolve the jumps through them.
+    ///
+    fn handle_call(
+        &mut self,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        registry: &mut Registry,
+        block_stack: &mut Stack,
+        block_element: &mut BlockElement,
+        queue_element: &mut QueueElement,
+        queue: &mut Vec<QueueElement>,
+    ) -> bool {
+        let code_type = queue_element.block_key.code_type;
+        let block_key = match block_stack.elements.last() {
+            Some(StackElement::Tag(destination)) => {
+                Self::block_key(code_type, destination.to_owned())
+            }
+            _ => return false,
+        };
+
+        let height = block_stack.elements.len() - 1;
+        for return_index in (0..height).rev() {
+            let return_key = match block_stack.elements[return_index] {
+                StackElement::Tag(ref tag) => Self::block_key(code_type, tag.to_owned()),
+                StackElement::ReturnAddress => break,
+                _ => continue,
+            };
+            if !blocks.contains_key(&return_key) {
+                continue;
+            }
+
+            let mut initial_stack = Stack::new();
+            initial_stack.push(StackElement::ReturnAddress);
+            for element in block_stack.elements[return_index + 1..height].iter() {
+                initial_stack.push(match element {
+                    StackElement::Tag(tag) => StackElement::Tag(tag.to_owned()),
+                    _ => StackElement::Value,
+                });
+            }
+            let input_size = initial_stack.elements.len() - 1;
+
+            let name = match registry.recover(
+                &self.solc_version,
+                blocks,
+                block_key.clone(),
+                initial_stack,
+            ) {
+                Some(name) => name,
+                None => continue,
+            };
+            self.calls.insert(name.clone());
+
+            queue_element.predecessor = Some(queue_element.block_key.clone());
+
+            block_element.stack = block_stack.clone();
+            block_stack.elements.truncate(return_index);
+            let output_size = registry.output_size(name.as_str());
+            match output_size {
+                Some(output_size) => {
+                    for _ in 0..output_size {
+                        block_stack.push(StackElement::Value);
+                    }
+                    queue.push(QueueElement::new(
+                        return_key.clone(),
+                        queue_element.predecessor.clone(),
+                        block_stack.to_owned(),
+                    ));
+                }
+                None if registry.is_recovered(name.as_str()) => {}
+                None => self.pending_calls.push(PendingCall::new(
+                    VisitedElement::new(
+                        queue_element.block_key.clone(),
+                        queue_element.stack.hash(),
+                    ),
+                    name.clone(),
+                    return_key.clone(),
+                    block_stack.to_owned(),
+                )),
+            }
+            block_element.call = Some(Call::new(name, input_size, output_size, return_key));
+
+            return true;
+        }
+
+        false
+    }
+
+    ///
+    /// Processes the return from the recovered function, checking the number of return values
+    /// against the other returns.
+    ///
+    fn handle_return(
+        &mut self,
+        registry: &mut Registry,
+        block_stack: &mut Stack,
+        block_element: &mut BlockElement,
+    ) -> anyhow::Result<()> {
+        block_element.stack = block_stack.clone();
+        block_stack.pop()?;
+
+        if block_stack
+            .elements
+            .iter()
+            .any(|element| matches!(element, StackElement::Tag(_)))
+        {
+            anyhow::bail!("Returning tags is not supported");
+        }
+
+        let output_size = block_stack.elements.len();
+        if let Type::Recovered {
+            output_size: ref mut expected,
+            ..
+        } = self.r#type
+        {
+            match expected {
+                Some(expected) if *expected != output_size => {
+                    anyhow::bail!("Expected {} return values, found {}", expected, output_size)
+                }
+                _ => *expected = Some(output_size),
+            }
+        }
+        registry.set_output_size(self.name.as_str(), output_size);
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the key of the block at the `destination` tag, which may point to the runtime code
+    /// from the deploy code.
+    ///
+    fn block_key(
+        code_type: compiler_llvm_context::CodeType,
+        destination: num::BigUint,
+    ) -> FunctionBlockKey {
+        if destination > num::BigUint::from(u32::MAX) {
+            FunctionBlockKey::new(
+                compiler_llvm_context::CodeType::Runtime,
+                destination - num::BigUint::from(1u64 << 32),
+            )
+        } else {
+            FunctionBlockKey::new(code_type, destination)
+        }
+    }
+
     ///
     /// Processes an instruction, returning an error, if there is an invalid stack state.
     ///
@@ -166,6 +554,7 @@ impl Function {
             Instruction {
                 name: InstructionName::PUSH_Tag,
                 value: Some(ref tag),
+                ..
             } => {
                 let tag: num::BigUint = tag.parse().expect("Always valid");
                 block_stack.push(Element::Tag(tag.bitand(num::BigUint::from(u64::MAX))));
@@ -180,14 +569,7 @@ impl Function {
 
                 block_element.stack = block_stack.clone();
                 let destination = block_stack.pop_tag()?;
-                let block_key = if destination > num::BigUint::from(u32::MAX) {
-                    FunctionBlockKey::new(
-                        compiler_llvm_context::CodeType::Runtime,
-                        destination - num::BigUint::from(1u64 << 32),
-                    )
-                } else {
-                    FunctionBlockKey::new(code_type, destination)
-                };
+                let block_key = Self::block_key(code_type, destination);
                 queue.push(QueueElement::new(
                     block_key,
                     queue_element.predecessor.clone(),
@@ -202,14 +584,7 @@ impl Function {
 
                 block_element.stack = block_stack.clone();
                 let destination = block_stack.pop_tag()?;
-                let block_key = if destination > num::BigUint::from(u32::MAX) {
-                    FunctionBlockKey::new(
-                        compiler_llvm_context::CodeType::Runtime,
-                        destination - num::BigUint::from(1u64 << 32),
-                    )
-                } else {
-                    FunctionBlockKey::new(code_type, destination)
-                };
+                let block_key = Self::block_key(code_type, destination);
                 block_stack.pop()?;
                 queue.push(QueueElement::new(
                     block_key,
@@ -220,6 +595,7 @@ impl Function {
             Instruction {
                 name: InstructionName::Tag,
                 value: Some(ref tag),
+                ..
             } => {
                 block_element.stack = block_stack.clone();
 
@@ -461,11 +837,32 @@ impl Function {
                 block_element.stack = block_stack.clone();
             }
 
+            Instruction {
+                name: InstructionName::PUSH0,
+                ..
+            } => {
+                block_stack.push(StackElement::Constant(num::BigUint::zero()));
+                block_element.stack = block_stack.clone();
+            }
+            Instruction {
+                name: InstructionName::PUSH_Data,
+                value: Some(ref data),
+                ..
+            } => {
+                block_stack.push(StackElement::Data(data.to_owned()));
+                block_element.stack = block_stack.clone();
+            }
+            Instruction {
+                name: InstructionName::PUSH_ContractHash,
+                value: Some(ref path),
+                ..
+            } => {
+                block_stack.push(StackElement::Path(path.to_owned()));
+                block_element.stack = block_stack.clone();
+            }
             Instruction {
                 name:
                     InstructionName::PUSH
-                    | InstructionName::PUSH_Data
-                    | InstructionName::PUSH_ContractHash
                     | InstructionName::PUSH_ContractHashSize
                     | InstructionName::PUSH1
                     | InstructionName::PUSH2
@@ -502,6 +899,7 @@ impl Function {
                     | InstructionName::PUSHLIB
                     | InstructionName::PUSHDEPLOYADDRESS,
                 value: Some(ref constant),
+                ..
             } => {
                 let element = match num::BigUint::from_str_radix(
                     constant.as_str(),
@@ -741,26 +1139,40 @@ impl Function {
     }
 
     ///
-    /// Pushes a block into the function.
+    /// Pushes a block into the function, replacing the clone with the same tags on the stack.
     ///
-    fn insert_block(&mut self, block: Block) -> &mut Block {
-        let key = block.key.clone();
-
-        if let Some(entry) = self.blocks.get_mut(&key) {
-            if entry.iter().all(|existing_block| {
-                existing_block.initial_stack.hash() != block.initial_stack.hash()
-            }) {
-                entry.push(block);
-            }
-        } else {
-            self.blocks.insert(key.clone(), vec![block]);
+    fn insert_block(&mut self, block: Block) {
+        let stack_hash = block.initial_stack.hash();
+        match self.find_block_mut(&block.key, &stack_hash) {
+            Some(clone) => *clone = block,
+            None => self
+                .blocks
+                .entry(block.key.clone())
+                .or_default()
+                .push(block),
         }
+    }
+
+    ///
+    /// Finds the block clone by its key and the initial stack state hash.
+    ///
+    fn find_block_mut(
+        &mut self,
+        key: &compiler_llvm_context::FunctionBlockKey,
+        stack_hash: &md5::Digest,
+    ) -> Option<&mut Block> {
+        self.blocks.get_mut(key).and_then(|blocks| {
+            blocks
+                .iter_mut()
+                .find(|block| block.initial_stack.hash() == *stack_hash)
+        })
+    }
 
-        self.blocks
-            .get_mut(&key)
-            .expect("Always exists")
-            .last_mut()
-            .expect("Always exists")
+    ///
+    /// Returns the number of block clones.
+    ///
+    pub fn clones_count(&self) -> usize {
+        self.blocks.values().map(Vec::len).sum()
     }
 
     ///
@@ -769,6 +1181,9 @@ impl Function {
     fn finalize(mut self) -> Self {
         for (_tag, blocks) in self.blocks.iter() {
             for block in blocks.iter() {
+                if block.initial_stack.elements.len() > self.stack_size {
+                    self.stack_size = block.initial_stack.elements.len();
+                }
                 for block_element in block.elements.iter() {
                     if block_element.stack.elements.len() > self.stack_size {
                         self.stack_size = block_element.stack.elements.len();
@@ -786,17 +1201,56 @@ where
     D: compiler_llvm_context::Dependency,
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        context.add_function_evm(
-            EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME,
-            context.void_type().fn_type(
-                &[context
-                    .integer_type(compiler_common::BITLENGTH_BOOLEAN as usize)
-                    .as_basic_type_enum()],
-                false,
-            ),
-            Some(inkwell::module::Linkage::Private),
-            compiler_llvm_context::FunctionEVMData::new(self.stack_size),
-        );
+        match self.r#type {
+            Type::Initial => {
+                context.add_function_evm(
+                    EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME,
+                    context.void_type().fn_type(
+                        &[context
+                            .integer_type(compiler_common::BITLENGTH_BOOLEAN as usize)
+                            .as_basic_type_enum()],
+                        false,
+                    ),
+                    Some(inkwell::module::Linkage::Private),
+                    compiler_llvm_context::FunctionEVMData::new(self.stack_size),
+                );
+            }
+            Type::Recovered {
+                input_size,
+                output_size,
+                ..
+            } => {
+                let output_size = output_size.unwrap_or_default();
+                let function_type = context.function_type(
+                    output_size,
+                    vec![context.field_type().as_basic_type_enum(); input_size],
+                );
+                context.add_function_evm(
+                    self.name.as_str(),
+                    function_type,
+                    Some(inkwell::module::Linkage::Private),
+                    compiler_llvm_context::FunctionEVMData::new(self.stack_size),
+                );
+
+                if output_size > 1 {
+                    let function = context
+                        .functions
+                        .get(self.name.as_str())
+                        .cloned()
+                        .ok_or_else(|| anyhow::anyhow!("Function `{}` not found", self.name))?;
+                    let pointer = function
+                        .value
+                        .get_first_param()
+                        .expect("Always exists")
+                        .into_pointer_value();
+                    context.set_function(function);
+                    context.set_function_return(compiler_llvm_context::FunctionReturn::compound(
+                        pointer,
+                        output_size,
+                    ));
+                }
+            }
+        }
 
         Ok(())
     }
@@ -804,15 +1258,10 @@ where
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         let function = context
             .functions
-            .get(EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME)
+            .get(self.name.as_str())
             .cloned()
             .expect("Always exists");
-        let is_constructor_flag = function
-            .value
-            .get_first_param()
-            .expect("Always exists")
-            .into_int_value();
-        context.set_function(function);
+        context.set_function(function.clone());
 
         for (key, blocks) in self.blocks.iter() {
             for (index, block) in blocks.iter().enumerate() {
@@ -841,25 +1290,68 @@ where
         }
         context.evm_mut().stack = stack_variables;
 
-        let constructor_block = context.function().evm().find_block(
-            &FunctionBlockKey::new(
-                compiler_llvm_context::CodeType::Deploy,
-                num::BigUint::zero(),
-            ),
-            &Stack::default().hash(),
-        )?;
-        let selector_block = context.function().evm().find_block(
-            &FunctionBlockKey::new(
-                compiler_llvm_context::CodeType::Runtime,
-                num::BigUint::zero(),
-            ),
-            &Stack::default().hash(),
-        )?;
-        context.build_conditional_branch(
-            is_constructor_flag,
-            constructor_block.inner,
-            selector_block.inner,
-        );
+        match self.r#type {
+            Type::Initial => {
+                let is_constructor_flag = function
+                    .value
+                    .get_first_param()
+                    .expect("Always exists")
+                    .into_int_value();
+                let constructor_block = context.function().evm().find_block(
+                    &FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Deploy,
+                        num::BigUint::zero(),
+                    ),
+                    &Stack::default().hash(),
+                )?;
+                let selector_block = context.function().evm().find_block(
+                    &FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Runtime,
+                        num::BigUint::zero(),
+                    ),
+                    &Stack::default().hash(),
+                )?;
+                context.build_conditional_branch(
+                    is_constructor_flag,
+                    constructor_block.inner,
+                    selector_block.inner,
+                );
+            }
+            Type::Recovered {
+                ref block_key,
+                stack_hash,
+                input_size,
+                output_size,
+            } => {
+                let parameter_offset = match function.r#return {
+                    Some(compiler_llvm_context::FunctionReturn::Compound { .. }) => 1,
+                    _ => 0,
+                };
+                // The return address slot at the bottom is left unused.
+                for index in 0..input_size {
+                    let argument = function
+                        .value
+                        .get_nth_param((parameter_offset + index) as u32)
+                        .expect("Always exists");
+                    let pointer = context.evm().stack[index + 1]
+                        .to_llvm()
+                        .into_pointer_value();
+                    context.build_store(pointer, argument);
+                }
+                if let Some(1) = output_size {
+                    let pointer = context.build_alloca(context.field_type(), "return_pointer");
+                    context.set_function_return(compiler_llvm_context::FunctionReturn::primitive(
+                        pointer,
+                    ));
+                }
+
+                let entry_block = context
+                    .function()
+                    .evm()
+                    .find_block(block_key, &stack_hash)?;
+                context.build_unconditional_branch(entry_block.inner);
+            }
+        }
 
         for (key, blocks) in self.blocks.into_iter() {
             for (llvm_block, ir_block) in context
@@ -882,7 +1374,16 @@ where
         context.build_throw_block(false);
 
         context.set_basic_block(context.function().return_block);
-        context.build_return(None);
+        match context.function().r#return {
+            Some(compiler_llvm_context::FunctionReturn::Primitive { pointer }) => {
+                let return_value = context.build_load(pointer, "return_value");
+                context.build_return(Some(&return_value));
+            }
+            Some(compiler_llvm_context::FunctionReturn::Compound { pointer, .. }) => {
+                context.build_return(Some(&pointer));
+            }
+            _ => context.build_return(None),
+        }
 
         Ok(())
     }
@@ -890,7 +1391,31 @@ where
 
 impl std::fmt::Display for Function {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "function main (max_sp = {}) {{", self.stack_size,)?;
+        match self.r#type {
+            Type::Initial => writeln!(
+                f,
+                "function main (max_sp = {}, blocks = {}, clones = {}) {{",
+                self.stack_size,
+                self.blocks.len(),
+                self.clones_count(),
+            )?,
+            Type::Recovered {
+                input_size,
+                output_size,
+                ..
+            } => writeln!(
+                f,
+                "function {} (input_size = {}, output_size = {}, max_sp = {}, blocks = {}, clones = {}) {{",
+                self.name,
+                input_size,
+                output_size
+                    .map(|output_size| output_size.to_string())
+                    .unwrap_or_else(|| "noreturn".to_owned()),
+                self.stack_size,
+                self.blocks.len(),
+                self.clones_count(),
+            )?,
+        }
         for (key, blocks) in self.blocks.iter() {
             for (index, block) in blocks.iter().enumerate() {
                 writeln!(
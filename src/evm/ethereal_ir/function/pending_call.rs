@@ -0,0 +1,43 @@
+//!
+//! The Ethereal IR pending function call.
+//!
+
+use crate::evm::ethereal_ir::function::block::element::stack::Stack;
+use crate::evm::ethereal_ir::function::visited_element::VisitedElement;
+
+///
+/// The Ethereal IR pending function call.
+///
+/// The call to a function being recovered, whose number of return values is not known yet, so
+/// the return block is consumed later.
+///
+#[derive(Debug, Clone)]
+pub struct PendingCall {
+    /// The calling block.
+    pub block: VisitedElement,
+    /// The callee function name.
+    pub name: String,
+    /// The block the callee returns to.
+    pub return_key: compiler_llvm_context::FunctionBlockKey,
+    /// The stack state below the return address.
+    pub stack: Stack,
+}
+
+impl PendingCall {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        block: VisitedElement,
+        name: String,
+        return_key: compiler_llvm_context::FunctionBlockKey,
+        stack: Stack,
+    ) -> Self {
+        Self {
+            block,
+            name,
+            return_key,
+            stack,
+        }
+    }
+}
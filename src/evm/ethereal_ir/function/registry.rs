@@ -0,0 +1,156 @@
+//!
+//! The Ethereal IR recovered function registry.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::evm::ethereal_ir::function::block::element::stack::Stack;
+use crate::evm::ethereal_ir::function::block::Block;
+use crate::evm::ethereal_ir::function::visited_element::VisitedElement;
+use crate::evm::ethereal_ir::function::Function;
+
+///
+/// The Ethereal IR recovered function registry.
+///
+/// Each internal function is recovered once per entry block and initial stack state, which
+/// differ in the number of arguments and the tags passed as function pointers.
+///
+#[derive(Debug, Default)]
+pub struct Registry {
+    /// The recovered function names, or `None` for the failed recoveries.
+    entries: HashMap<VisitedElement, Option<String>>,
+    /// The numbers of return values found for the functions being recovered or recovered.
+    output_sizes: HashMap<String, usize>,
+    /// The recovered functions.
+    functions: BTreeMap<String, Function>,
+    /// The numbers of functions recovered at each entry block, used for the names.
+    counters: HashMap<compiler_llvm_context::FunctionBlockKey, usize>,
+}
+
+impl Registry {
+    ///
+    /// Recovers the function entered at `block_key` with `initial_stack`, returning its name,
+    /// or `None` if it must be inlined.
+    ///
+    /// The name of a function being recovered is returned at once, so it can call itself.
+    ///
+    pub fn recover(
+        &mut self,
+        solc_version: &semver::Version,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        block_key: compiler_llvm_context::FunctionBlockKey,
+        initial_stack: Stack,
+    ) -> Option<String> {
+        let entry = VisitedElement::new(block_key.clone(), initial_stack.hash());
+        if let Some(name) = self.entries.get(&entry) {
+            return name.to_owned();
+        }
+
+        let counter = self.counters.entry(block_key.clone()).or_default();
+        let name = format!(
+            "function_{}_{}/{}",
+            match block_key.code_type {
+                compiler_llvm_context::CodeType::Deploy => "deploy",
+                compiler_llvm_context::CodeType::Runtime => "runtime",
+            },
+            block_key.tag,
+            counter
+        );
+        *counter += 1;
+        self.entries.insert(entry.clone(), Some(name.clone()));
+
+        match Function::try_recovered(
+            solc_version.to_owned(),
+            name.clone(),
+            block_key,
+            initial_stack,
+            blocks,
+            self,
+        ) {
+            Ok(function) => {
+                self.functions.insert(name.clone(), function);
+                Some(name)
+            }
+            Err(_error) => {
+                self.entries.insert(entry, None);
+                self.invalidate(name);
+                None
+            }
+        }
+    }
+
+    ///
+    /// Returns the number of return values of the function, if it has been found.
+    ///
+    pub fn output_size(&self, name: &str) -> Option<usize> {
+        self.output_sizes.get(name).copied()
+    }
+
+    ///
+    /// Sets the number of return values of the function being recovered.
+    ///
+    pub fn set_output_size(&mut self, name: &str, output_size: usize) {
+        self.output_sizes.insert(name.to_owned(), output_size);
+    }
+
+    ///
+    /// Checks whether the function recovery has been completed.
+    ///
+    pub fn is_recovered(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    ///
+    /// Returns the recovered functions called by the `entry` function, directly or not.
+    ///
+    pub fn into_functions(mut self, entry: &Function) -> Vec<Function> {
+        let mut functions = Vec::with_capacity(self.functions.len());
+        let mut queue: Vec<String> = entry.calls.iter().cloned().collect();
+        while let Some(name) = queue.pop() {
+            if let Some(function) = self.functions.remove(name.as_str()) {
+                queue.extend(function.calls.iter().cloned());
+                functions.push(function);
+            }
+        }
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+        functions
+    }
+
+    ///
+    /// Removes the failed function and the recovered functions calling it, directly or not,
+    /// which were recovered while it was being recovered.
+    ///
+    fn invalidate(&mut self, name: String) {
+        let mut invalid = HashSet::with_capacity(self.functions.len());
+        invalid.insert(name);
+        loop {
+            let callers: Vec<String> = self
+                .functions
+                .iter()
+                .filter(|(name, function)| {
+                    !invalid.contains(name.as_str())
+                        && function
+                            .calls
+                            .iter()
+                            .any(|callee| invalid.contains(callee.as_str()))
+                })
+                .map(|(name, _function)| name.to_owned())
+                .collect();
+            if callers.is_empty() {
+                break;
+            }
+            invalid.extend(callers);
+        }
+
+        self.functions
+            .retain(|name, _function| !invalid.contains(name));
+        self.output_sizes
+            .retain(|name, _output_size| !invalid.contains(name));
+        self.entries.retain(|_entry, name| match name {
+            Some(name) => !invalid.contains(name),
+            None => true,
+        });
+    }
+}
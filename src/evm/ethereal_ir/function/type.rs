@@ -0,0 +1,48 @@
+//!
+//! The Ethereal IR function type.
+//!
+
+///
+/// The Ethereal IR function type.
+///
+#[derive(Debug, Clone)]
+pub enum Type {
+    /// The initial function, combining the deploy and runtime code.
+    Initial,
+    /// The internal function recovered from the `JUMP [in]` calls.
+    Recovered {
+        /// The entry block key.
+        block_key: compiler_llvm_context::FunctionBlockKey,
+        /// The entry block initial stack state hash.
+        stack_hash: md5::Digest,
+        /// The number of arguments.
+        input_size: usize,
+        /// The number of return values, or `None` if the function never returns.
+        output_size: Option<usize>,
+    },
+}
+
+impl Type {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_initial() -> Self {
+        Self::Initial
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_recovered(
+        block_key: compiler_llvm_context::FunctionBlockKey,
+        stack_hash: md5::Digest,
+        input_size: usize,
+    ) -> Self {
+        Self::Recovered {
+            block_key,
+            stack_hash,
+            input_size,
+            output_size: None,
+        }
+    }
+}
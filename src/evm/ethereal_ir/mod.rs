@@ -6,11 +6,11 @@ pub mod entry_link;
 pub mod function;
 
 use std::collections::HashMap;
-use std::collections::HashSet;
 
 use crate::evm::assembly::instruction::Instruction;
 
 use self::function::block::Block;
+use self::function::registry::Registry;
 use self::function::Function;
 
 ///
@@ -20,8 +20,11 @@ use self::function::Function;
 pub struct EtherealIR {
     /// The Solidity compiler version.
     pub solc_version: semver::Version,
-    /// The all-inlined function representation.
-    pub function: Function,
+    /// The entry function representation, where the functions which cannot be recovered are
+    /// inlined.
+    pub entry_function: Function,
+    /// The internal functions recovered from the `JUMP [in]` calls.
+    pub functions: Vec<Function>,
 }
 
 impl EtherealIR {
@@ -41,12 +44,14 @@ impl EtherealIR {
         solc_version: semver::Version,
         blocks: HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
     ) -> anyhow::Result<Self> {
-        let mut visited = HashSet::with_capacity(blocks.len());
-        let function = Function::new(solc_version.clone(), &blocks, &mut visited)?;
+        let mut registry = Registry::default();
+        let entry_function = Function::try_initial(solc_version.clone(), &blocks, &mut registry)?;
+        let functions = registry.into_functions(&entry_function);
 
         Ok(Self {
             solc_version,
-            function,
+            entry_function,
+            functions,
         })
     }
 
@@ -83,7 +88,10 @@ where
     D: compiler_llvm_context::Dependency,
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        self.function.declare(context)?;
+        self.entry_function.declare(context)?;
+        for function in self.functions.iter_mut() {
+            function.declare(context)?;
+        }
 
         Ok(())
     }
@@ -91,7 +99,10 @@ where
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         context.evm_mut().stack = vec![];
 
-        self.function.into_llvm(context)?;
+        self.entry_function.into_llvm(context)?;
+        for function in self.functions.into_iter() {
+            function.into_llvm(context)?;
+        }
 
         Ok(())
     }
@@ -99,7 +110,10 @@ where
 
 impl std::fmt::Display for EtherealIR {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{}", self.function)?;
+        writeln!(f, "{}", self.entry_function)?;
+        for function in self.functions.iter() {
+            writeln!(f, "{}", function)?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,111 @@
+//!
+//! The target EVM version.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The target EVM version, named after the hard fork introducing it, as in solc.
+///
+/// Only gates the opcodes introduced by the newer hard forks, which are rejected if the target
+/// precedes them. The default is the newest version, so all the supported opcodes are accepted
+/// unless an older target is requested.
+///
+#[derive(
+    Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum EVMVersion {
+    /// The Homestead hard fork.
+    Homestead,
+    /// The Tangerine Whistle hard fork.
+    TangerineWhistle,
+    /// The Spurious Dragon hard fork.
+    SpuriousDragon,
+    /// The Byzantium hard fork.
+    Byzantium,
+    /// The Constantinople hard fork.
+    Constantinople,
+    /// The Petersburg hard fork.
+    Petersburg,
+    /// The Istanbul hard fork.
+    Istanbul,
+    /// The Berlin hard fork.
+    Berlin,
+    /// The London hard fork.
+    London,
+    /// The Paris hard fork, introducing `PREVRANDAO`.
+    Paris,
+    /// The Shanghai hard fork, introducing `PUSH0`.
+    Shanghai,
+    /// The Cancun hard fork, introducing `TLOAD`, `TSTORE`, `MCOPY`, `BLOBHASH`, and
+    /// `BLOBBASEFEE`.
+    #[default]
+    Cancun,
+}
+
+impl EVMVersion {
+    ///
+    /// Returns all the versions in the chronological order.
+    ///
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Homestead,
+            Self::TangerineWhistle,
+            Self::SpuriousDragon,
+            Self::Byzantium,
+            Self::Constantinople,
+            Self::Petersburg,
+            Self::Istanbul,
+            Self::Berlin,
+            Self::London,
+            Self::Paris,
+            Self::Shanghai,
+            Self::Cancun,
+        ]
+    }
+}
+
+impl FromStr for EVMVersion {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .into_iter()
+            .find(|version| version.to_string() == input)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown EVM version `{}`. Available versions: {}",
+                    input,
+                    Self::all()
+                        .into_iter()
+                        .map(|version| version.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for EVMVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Homestead => write!(f, "homestead"),
+            Self::TangerineWhistle => write!(f, "tangerineWhistle"),
+            Self::SpuriousDragon => write!(f, "spuriousDragon"),
+            Self::Byzantium => write!(f, "byzantium"),
+            Self::Constantinople => write!(f, "constantinople"),
+            Self::Petersburg => write!(f, "petersburg"),
+            Self::Istanbul => write!(f, "istanbul"),
+            Self::Berlin => write!(f, "berlin"),
+            Self::London => write!(f, "london"),
+            Self::Paris => write!(f, "paris"),
+            Self::Shanghai => write!(f, "shanghai"),
+            Self::Cancun => write!(f, "cancun"),
+        }
+    }
+}
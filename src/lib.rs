@@ -3,12 +3,19 @@
 //!
 
 pub(crate) mod dump_flag;
+pub(crate) mod environment;
 pub(crate) mod evm;
+pub(crate) mod evm_version;
 pub(crate) mod project;
 pub(crate) mod solc;
+pub(crate) mod stub_mode;
 pub(crate) mod yul;
 
 pub use self::dump_flag::DumpFlag;
+pub use self::evm_version::EVMVersion;
+pub use self::project::contract::source::evm::EVM as ProjectContractSourceEVM;
+pub use self::project::contract::source::yul::Yul as ProjectContractSourceYul;
+pub use self::project::contract::source::Source as ProjectContractSource;
 pub use self::project::contract::Contract as ProjectContract;
 pub use self::project::Project;
 pub use self::solc::combined_json::contract::Contract as SolcCombinedJsonContract;
@@ -24,6 +31,37 @@ pub use self::solc::standard_json::output::contract::evm::EVM as SolcStandardJso
 pub use self::solc::standard_json::output::contract::Contract as SolcStandardJsonOutputContract;
 pub use self::solc::standard_json::output::Output as SolcStandardJsonOutput;
 pub use self::solc::Compiler as SolcCompiler;
+pub use self::stub_mode::StubMode;
+pub use self::yul::lexer::lexeme::Lexeme as YulLexeme;
+pub use self::yul::lexer::token::location::Location as YulLocation;
+pub use self::yul::lexer::token::source_location::SourceLocation as YulSourceLocation;
+pub use self::yul::lexer::token::Token as YulToken;
+pub use self::yul::lexer::Lexer as YulLexer;
+pub use self::yul::location_eraser::LocationEraser as YulLocationEraser;
+pub use self::yul::optimizer::pass::Pass as YulOptimizerPass;
+pub use self::yul::optimizer::Optimizer as YulOptimizer;
+pub use self::yul::parser::identifier::Identifier as YulIdentifier;
+pub use self::yul::parser::r#type::Type as YulType;
+pub use self::yul::parser::statement::assignment::Assignment as YulAssignment;
+pub use self::yul::parser::statement::block::Block as YulBlock;
+pub use self::yul::parser::statement::code::Code as YulCode;
+pub use self::yul::parser::statement::expression::function_call::name::Name as YulFunctionName;
+pub use self::yul::parser::statement::expression::function_call::FunctionCall as YulFunctionCall;
+pub use self::yul::parser::statement::expression::literal::Literal as YulLiteral;
+pub use self::yul::parser::statement::expression::Expression as YulExpression;
+pub use self::yul::parser::statement::for_loop::ForLoop as YulForLoop;
+pub use self::yul::parser::statement::function_definition::FunctionDefinition as YulFunctionDefinition;
+pub use self::yul::parser::statement::if_conditional::IfConditional as YulIfConditional;
+pub use self::yul::parser::statement::object::data::Data as YulData;
+pub use self::yul::parser::statement::object::layout::Layout as YulObjectLayout;
+pub use self::yul::parser::statement::object::Object as YulObject;
+pub use self::yul::parser::statement::switch::case::Case as YulSwitchCase;
+pub use self::yul::parser::statement::switch::Switch as YulSwitch;
+pub use self::yul::parser::statement::variable_declaration::VariableDeclaration as YulVariableDeclaration;
+pub use self::yul::parser::statement::Statement as YulStatement;
+pub use self::yul::parser::visitor::Visitor as YulVisitor;
+pub use self::yul::parser::visitor_mut::VisitorMut as YulVisitorMut;
+pub use self::yul::source_map::SourceMap as YulSourceMap;
 
 ///
 /// Initializes the zkEVM target machine.
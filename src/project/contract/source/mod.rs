@@ -6,6 +6,7 @@ pub mod evm;
 pub mod yul;
 
 use crate::evm::assembly::Assembly;
+use crate::yul::ext_code_checker::ExtCodeChecker;
 use crate::yul::parser::statement::object::Object;
 
 use self::evm::EVM;
@@ -17,6 +18,7 @@ use self::yul::Yul;
 #[derive(Debug, Clone)]
 #[allow(non_camel_case_types)]
 #[allow(clippy::upper_case_acronyms)]
+#[allow(clippy::large_enum_variant)]
 pub enum Source {
     /// The Yul source representation.
     Yul(Yul),
@@ -38,6 +40,16 @@ impl Source {
     pub fn new_evm(full_path: String, assembly: Assembly) -> Self {
         Self::EVM(EVM::new(full_path, assembly))
     }
+
+    ///
+    /// Checks whether the contract code reads the code of other accounts.
+    ///
+    pub fn uses_ext_code(&self) -> bool {
+        match self {
+            Self::Yul(inner) => ExtCodeChecker::check(&inner.object),
+            Self::EVM(inner) => inner.assembly.uses_ext_code(),
+        }
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Source
@@ -4,8 +4,10 @@
 
 pub mod contract;
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::path::Path;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::RwLock;
@@ -16,12 +18,22 @@ use rayon::iter::ParallelIterator;
 use compiler_llvm_context::WriteLLVM;
 
 use crate::dump_flag::DumpFlag;
+use crate::evm_version::EVMVersion;
 use crate::project::contract::source::Source;
 use crate::solc::combined_json::CombinedJson;
 use crate::solc::standard_json::output::contract::evm::EVM as StandardJsonOutputContractEVM;
+use crate::solc::standard_json::output::error::Error as StandardJsonOutputError;
 use crate::solc::standard_json::output::Output as StandardJsonOutput;
-use crate::yul::lexer::Lexer;
+use crate::stub_mode::StubMode;
+use crate::yul::analyzer::Analyzer;
+use crate::yul::data_resolver::DataResolver;
+use crate::yul::mangler::Mangler;
+use crate::yul::optimizer::Optimizer;
+use crate::yul::parser::statement::object::layout::Layout;
 use crate::yul::parser::statement::object::Object;
+use crate::yul::source_map::SourceMap;
+use crate::yul::stub_checker::StubChecker;
+use crate::yul::stub_trapper::StubTrapper;
 
 use self::contract::Contract;
 
@@ -36,6 +48,8 @@ pub struct Project {
     pub contracts: HashMap<String, Contract>,
     /// The library addresses.
     pub libraries: HashMap<String, HashMap<String, String>>,
+    /// The Solidity source map for the Yul diagnostics.
+    pub source_map: SourceMap,
 }
 
 impl Project {
@@ -46,11 +60,13 @@ impl Project {
         version: semver::Version,
         contracts: HashMap<String, Contract>,
         libraries: HashMap<String, HashMap<String, String>>,
+        source_map: SourceMap,
     ) -> Self {
         Self {
             version,
             contracts,
             libraries,
+            source_map,
         }
     }
 
@@ -99,6 +115,10 @@ impl Project {
             Source::Yul(ref yul) => yul.object.identifier.to_owned(),
             Source::EVM(ref evm) => evm.full_path.to_owned(),
         };
+        let source_paths = match source {
+            Source::Yul(ref yul) => yul.object.sources.to_owned(),
+            Source::EVM(_) => BTreeMap::new(),
+        };
         let mut context = match source {
             Source::Yul(_) => compiler_llvm_context::Context::new(
                 &llvm,
@@ -128,14 +148,14 @@ impl Project {
             anyhow::anyhow!(
                 "The contract `{}` LLVM IR generator declaration pass error: {}",
                 contract_path,
-                error
+                Self::annotate_error(&project, &source_paths, error)
             )
         })?;
         source.into_llvm(&mut context).map_err(|error| {
             anyhow::anyhow!(
                 "The contract `{}` LLVM IR generator definition pass error: {}",
                 contract_path,
-                error
+                Self::annotate_error(&project, &source_paths, error)
             )
         })?;
         if dump_flags.contains(&compiler_llvm_context::DumpFlag::LLVM) {
@@ -302,6 +322,11 @@ impl Project {
         self,
         standard_json: &mut StandardJsonOutput,
     ) -> anyhow::Result<()> {
+        standard_json
+            .errors
+            .get_or_insert_with(Vec::new)
+            .extend(self.warnings());
+
         let contracts = match standard_json.contracts.as_mut() {
             Some(contracts) => contracts,
             None => return Ok(()),
@@ -332,6 +357,190 @@ impl Project {
         Ok(())
     }
 
+    ///
+    /// Returns the warnings on the contract code behaving differently on zkEVM, one per affected
+    /// contract.
+    ///
+    pub fn warnings(&self) -> Vec<StandardJsonOutputError> {
+        let mut paths: Vec<&String> = self.contracts.keys().collect();
+        paths.sort();
+
+        let mut warnings = Vec::new();
+        for path in paths.into_iter() {
+            if self.contracts[path].source.uses_ext_code() {
+                let mut warning = StandardJsonOutputError::warning_ext_code();
+                warning.push_contract_path(path.as_str());
+                warnings.push(warning);
+            }
+        }
+        warnings
+    }
+
+    ///
+    /// Appends the Solidity source location to the Yul lowering `error`, if it is known.
+    ///
+    fn annotate_error(
+        project: &Arc<RwLock<Self>>,
+        paths: &BTreeMap<usize, String>,
+        error: anyhow::Error,
+    ) -> anyhow::Error {
+        project
+            .read()
+            .unwrap()
+            .source_map
+            .annotate_error(paths, error)
+    }
+
+    ///
+    /// Checks the parsed Yul `object` of the contract at `full_path`, and prepares it for the
+    /// LLVM lowering.
+    ///
+    /// The function names are mangled and the data section references are resolved first, as
+    /// the checks and the optimizer passes rely on them.
+    ///
+    /// The contracts with semantic errors are rejected. In the strict `stub_mode`, the contracts
+    /// using the builtins without zkEVM equivalents are rejected, and in the trap mode, the
+    /// builtins are compiled to reverts. The builtins introduced by an EVM version newer than
+    /// the target `evm_version` are rejected. The diagnostics refer to the Solidity code through
+    /// the `source_map`, if the Yul code is annotated.
+    ///
+    pub fn prepare_yul_object(
+        object: &mut Object,
+        full_path: &str,
+        source_map: &SourceMap,
+        optimizer: &Optimizer,
+        stub_mode: StubMode,
+        evm_version: EVMVersion,
+        dump_flags: &[DumpFlag],
+    ) -> anyhow::Result<()> {
+        Mangler::mangle(object);
+        DataResolver::resolve(object);
+
+        let errors = Analyzer::analyze(object, evm_version);
+        if !errors.is_empty() {
+            return Err(Self::diagnostics_error(
+                format!("Contract `{}` semantic errors", full_path),
+                errors
+                    .into_iter()
+                    .map(|error| {
+                        source_map.annotate(&object.sources, error.location(), error.to_string())
+                    })
+                    .collect(),
+            ));
+        }
+
+        match stub_mode {
+            StubMode::Silent => {}
+            StubMode::Strict => {
+                let stubs = StubChecker::check(object);
+                if !stubs.is_empty() {
+                    return Err(Self::diagnostics_error(
+                        format!("Contract `{}` strict mode errors", full_path),
+                        stubs
+                            .into_iter()
+                            .map(|stub| {
+                                source_map.annotate(
+                                    &object.sources,
+                                    stub.location.clone(),
+                                    stub.to_string(),
+                                )
+                            })
+                            .collect(),
+                    ));
+                }
+            }
+            StubMode::Trap => StubTrapper::trap(object),
+        }
+
+        optimizer.optimize(object, full_path, dump_flags.contains(&DumpFlag::Yul));
+
+        Ok(())
+    }
+
+    ///
+    /// Joins the `diagnostics` into a single error, headed by the `subject`.
+    ///
+    pub fn diagnostics_error(subject: String, diagnostics: Vec<String>) -> anyhow::Error {
+        anyhow::anyhow!("{}:\n{}", subject, diagnostics.join("\n"))
+    }
+
+    ///
+    /// Parses the Yul source files and returns the source data.
+    ///
+    /// Each file may contain several top-level objects, which are selected as contracts by the
+    /// `layout`. The dependency objects found in their trees are added as separate contracts.
+    ///
+    /// In the strict `stub_mode`, the contracts using the builtins without zkEVM equivalents are
+    /// rejected, and in the trap mode, the builtins are compiled to reverts. The builtins
+    /// introduced by an EVM version newer than the target `evm_version` are rejected.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_from_yul_paths(
+        paths: &[PathBuf],
+        version: semver::Version,
+        layout: &Layout,
+        optimizer: &Optimizer,
+        stub_mode: StubMode,
+        evm_version: EVMVersion,
+        dump_flags: &[DumpFlag],
+    ) -> anyhow::Result<Self> {
+        let mut project_contracts = HashMap::with_capacity(paths.len());
+
+        for path in paths.iter() {
+            let path = path.to_string_lossy().to_string();
+            let source = std::fs::read_to_string(path.as_str())
+                .map_err(|error| anyhow::anyhow!("File `{}` reading error: {}", path, error))?;
+
+            let objects = match Object::parse_all_recovering(source.clone(), Some(path.as_str())) {
+                (objects, errors) if errors.is_empty() => objects,
+                (_, errors) => {
+                    return Err(Self::diagnostics_error(
+                        format!("File `{}` parsing errors", path),
+                        errors.into_iter().map(|error| error.to_string()).collect(),
+                    ))
+                }
+            };
+            let contracts = layout
+                .apply(objects)
+                .map_err(|error| anyhow::anyhow!("File `{}` layout error: {}", path, error))?;
+
+            for contract in contracts.into_iter() {
+                let dependencies = contract.nested_dependencies();
+                for mut object in std::iter::once(contract).chain(dependencies) {
+                    let full_path = format!("{}:{}", path, object.identifier);
+
+                    if dump_flags.contains(&DumpFlag::Yul) {
+                        eprintln!("Contract `{}` Yul:\n", full_path);
+                        println!("{}", object);
+                    }
+
+                    Self::prepare_yul_object(
+                        &mut object,
+                        full_path.as_str(),
+                        &SourceMap::default(),
+                        optimizer,
+                        stub_mode,
+                        evm_version,
+                        dump_flags,
+                    )?;
+
+                    let name = object.identifier.clone();
+                    project_contracts.insert(
+                        full_path.clone(),
+                        Contract::new(full_path, name, Source::new_yul(source.clone(), object)),
+                    );
+                }
+            }
+        }
+
+        Ok(Self::new(
+            version,
+            project_contracts,
+            HashMap::new(),
+            SourceMap::default(),
+        ))
+    }
+
     ///
     /// Parses the test Yul source code and returns the source data.
     ///
@@ -345,10 +554,25 @@ impl Project {
         )
         .expect("Always valid");
 
-        let mut lexer = Lexer::new(yul.to_owned());
         let name = "Test".to_owned();
-        let object = Object::parse(&mut lexer, None)
-            .map_err(|error| anyhow::anyhow!("Yul object `{}` parsing error: {}", name, error,))?;
+        let mut object = match Object::parse_recovering(yul.to_owned(), None) {
+            (Some(object), errors) if errors.is_empty() => object,
+            (_, errors) => {
+                return Err(Self::diagnostics_error(
+                    format!("Yul object `{}` parsing errors", name),
+                    errors.into_iter().map(|error| error.to_string()).collect(),
+                ))
+            }
+        };
+        Self::prepare_yul_object(
+            &mut object,
+            name.as_str(),
+            &SourceMap::default(),
+            &Optimizer::default(),
+            StubMode::Silent,
+            EVMVersion::default(),
+            &[],
+        )?;
 
         let mut project_contracts = HashMap::with_capacity(1);
         project_contracts.insert(
@@ -359,6 +583,7 @@ impl Project {
             version,
             contracts: project_contracts,
             libraries: HashMap::new(),
+            source_map: SourceMap::default(),
         })
     }
 }
@@ -457,3 +682,169 @@ impl compiler_llvm_context::Dependency for Project {
         anyhow::bail!("Library `{}` not found in the project", path);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::RwLock;
+
+    use crate::evm_version::EVMVersion;
+    use crate::project::contract::source::Source;
+    use crate::stub_mode::StubMode;
+    use crate::yul::optimizer::Optimizer;
+    use crate::yul::parser::statement::expression::function_call::FunctionCall;
+    use crate::yul::parser::statement::object::layout::Layout;
+    use crate::yul::parser::statement::object::Object;
+    use crate::yul::parser::visitor::Visitor;
+
+    use super::Project;
+
+    ///
+    /// Counts the calls marked by the stubbed builtin trapper.
+    ///
+    #[derive(Default)]
+    struct TrapCounter {
+        /// The number of the trapped calls.
+        count: usize,
+    }
+
+    impl Visitor for TrapCounter {
+        fn visit_object(&mut self, object: &Object) {
+            self.visit_code(&object.code);
+            if let Some(ref runtime) = object.object {
+                self.visit_object(runtime);
+            }
+        }
+
+        fn visit_function_call(&mut self, call: &FunctionCall) {
+            if call.is_trapped {
+                self.count += 1;
+            }
+            self.walk_function_call(call);
+        }
+    }
+
+    /// The factory contract, which deploys its child contract.
+    const FACTORY: &str = r#"
+object "Factory" {
+    code { return(0, 0) }
+    object "Factory_deployed" {
+        code {
+            let size := datasize("Child")
+            datacopy(0, dataoffset("Child"), size)
+            sstore(0, create(0, 0, size))
+        }
+        object "Child" {
+            code { return(0, 0) }
+            object "Child_deployed" {
+                code { stop() }
+            }
+        }
+    }
+}
+"#;
+
+    /// The contract copying its data section.
+    const DATA: &str = r#"
+object "Data" {
+    code { return(0, 0) }
+    object "Data_deployed" {
+        code {
+            datacopy(0, dataoffset("D"), datasize("D"))
+            return(0, datasize("D"))
+        }
+        data "D" hex"0102"
+    }
+}
+"#;
+
+    ///
+    /// Parses the Yul `source` as the file `name` in the `stub_mode`.
+    ///
+    fn project(name: &str, source: &str, stub_mode: StubMode) -> anyhow::Result<Project> {
+        let path = std::env::temp_dir().join(format!("zksolc_{}_{}.yul", std::process::id(), name));
+        std::fs::write(&path, source).expect("Temporary file writing error");
+        let project = Project::try_from_yul_paths(
+            std::slice::from_ref(&path),
+            semver::Version::new(0, 8, 0),
+            &Layout::default(),
+            &Optimizer::default(),
+            stub_mode,
+            EVMVersion::default(),
+            &[],
+        );
+        std::fs::remove_file(path).expect("Temporary file removing error");
+        project
+    }
+
+    ///
+    /// Compiles the `project`, and returns the factory dependency paths of the contract `name`.
+    ///
+    fn compile(project: Project, name: &str) -> Vec<String> {
+        crate::initialize_target();
+        let project = Arc::new(RwLock::new(project));
+        Project::compile_all(project.clone(), false, vec![]).expect("Compiling error");
+
+        let project = project.read().unwrap();
+        project
+            .contracts
+            .values()
+            .find(|contract| contract.name == name)
+            .expect("Always exists")
+            .factory_dependencies
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn strict_mode_accepts_factory() {
+        let project =
+            project("strict_factory", FACTORY, StubMode::Strict).expect("Strict mode error");
+        let dependencies = compile(project, "Factory");
+        assert!(dependencies.iter().any(|path| path.ends_with(":Child")));
+    }
+
+    #[test]
+    fn strict_mode_accepts_data_section() {
+        let project = project("strict_data", DATA, StubMode::Strict).expect("Strict mode error");
+        assert!(compile(project, "Data").is_empty());
+    }
+
+    #[test]
+    fn strict_mode_rejects_unmodeled_datacopy() {
+        let source = DATA.replace(r#"dataoffset("D")"#, "5");
+        let error = project("strict_unmodeled", source.as_str(), StubMode::Strict)
+            .expect_err("Unmodeled `datacopy` must be rejected");
+        assert!(error.to_string().contains("`datacopy`"));
+    }
+
+    #[test]
+    fn trap_mode_keeps_factory() {
+        let project = project("trap_factory", FACTORY, StubMode::Trap).expect("Trap mode error");
+        for contract in project.contracts.values() {
+            if let Source::Yul(ref yul) = contract.source {
+                let mut counter = TrapCounter::default();
+                counter.visit_object(&yul.object);
+                assert_eq!(counter.count, 0, "`{}` is trapped", contract.path);
+            }
+        }
+
+        let dependencies = compile(project, "Factory");
+        assert!(dependencies.iter().any(|path| path.ends_with(":Child")));
+    }
+
+    #[test]
+    fn trap_mode_traps_unmodeled_datacopy() {
+        let source = DATA.replace(r#"dataoffset("D")"#, "5");
+        let project =
+            project("trap_unmodeled", source.as_str(), StubMode::Trap).expect("Trap mode error");
+        let mut counter = TrapCounter::default();
+        for contract in project.contracts.values() {
+            if let Source::Yul(ref yul) = contract.source {
+                counter.visit_object(&yul.object);
+            }
+        }
+        assert_eq!(counter.count, 1);
+    }
+}
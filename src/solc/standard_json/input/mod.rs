@@ -78,4 +78,13 @@ impl Input {
             settings: Settings::new(libraries, output_selection, optimize),
         })
     }
+    ///
+    /// Returns the source code of the input files, by their paths.
+    ///
+    pub fn source_code(&self) -> HashMap<String, String> {
+        self.sources
+            .iter()
+            .map(|(path, source)| (path.to_owned(), source.content.to_owned()))
+            .collect()
+    }
 }
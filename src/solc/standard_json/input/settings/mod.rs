@@ -10,6 +10,7 @@ use std::collections::HashMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::evm_version::EVMVersion;
 use crate::solc::pipeline::Pipeline as SolcPipeline;
 
 use self::optimizer::Optimizer;
@@ -28,6 +29,12 @@ pub struct Settings {
     pub output_selection: serde_json::Value,
     /// The optimizer settings.
     pub optimizer: Optimizer,
+    /// The target EVM version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<EVMVersion>,
+    /// Whether to reject the operations without zkEVM equivalents. Not passed to solc.
+    #[serde(default, skip_serializing)]
+    pub strict: bool,
 }
 
 impl Settings {
@@ -43,6 +50,8 @@ impl Settings {
             libraries: Some(libraries),
             output_selection,
             optimizer: Optimizer::new(optimize),
+            evm_version: None,
+            strict: false,
         }
     }
 
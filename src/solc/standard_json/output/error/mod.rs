@@ -87,6 +87,27 @@ impl Error {
         }
     }
 
+    ///
+    /// Returns the warning on the external code instructions, which behave differently on zkEVM.
+    ///
+    pub fn warning_ext_code() -> Self {
+        let message = "Warning: `extcodesize` and `extcodehash` return the size and hash of the \
+zkEVM bytecode, as stored in the account code storage, which differ from the EVM ones for the \
+same source code. `extcodecopy` fills the destination with zeros, as the zkEVM bytecode cannot be \
+read by other contracts."
+            .to_owned();
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
     ///
     /// Appends the contract path to the message..
     ///
@@ -6,6 +6,7 @@ pub mod contract;
 pub mod error;
 pub mod source;
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use serde::Deserialize;
@@ -14,12 +15,15 @@ use serde::Serialize;
 use crate::dump_flag::DumpFlag;
 use crate::evm::assembly::instruction::Instruction;
 use crate::evm::assembly::Assembly;
+use crate::evm_version::EVMVersion;
 use crate::project::contract::source::Source as ProjectContractSource;
 use crate::project::contract::Contract as ProjectContract;
 use crate::project::Project;
 use crate::solc::pipeline::Pipeline as SolcPipeline;
-use crate::yul::lexer::Lexer;
+use crate::stub_mode::StubMode;
+use crate::yul::optimizer::Optimizer;
 use crate::yul::parser::statement::object::Object;
+use crate::yul::source_map::SourceMap;
 
 use self::contract::Contract;
 use self::error::Error as SolcStandardJsonOutputError;
@@ -45,11 +49,22 @@ impl Output {
     ///
     /// Converts the `solc` JSON output into a convenient project representation.
     ///
+    /// In the strict `stub_mode`, the contracts using the operations without zkEVM equivalents
+    /// are rejected, and in the trap mode, the operations are compiled to reverts.
+    ///
+    /// The contracts using the opcodes unknown to the compiler, or introduced by an EVM version
+    /// newer than the target `evm_version`, are rejected.
+    ///
+    #[allow(clippy::too_many_arguments)]
     pub fn try_into_project(
         &mut self,
         libraries: HashMap<String, HashMap<String, String>>,
         pipeline: SolcPipeline,
         version: semver::Version,
+        source_map: SourceMap,
+        optimizer: &Optimizer,
+        stub_mode: StubMode,
+        evm_version: EVMVersion,
         dump_flags: &[DumpFlag],
     ) -> anyhow::Result<Project> {
         self.preprocess_ast()?;
@@ -70,6 +85,16 @@ impl Output {
                 );
             }
         };
+        let source_paths: BTreeMap<usize, String> = self
+            .sources
+            .as_ref()
+            .map(|sources| {
+                sources
+                    .iter()
+                    .map(|(path, source)| (source.id, path.to_owned()))
+                    .collect()
+            })
+            .unwrap_or_default();
         let mut project_contracts = HashMap::with_capacity(files.len());
 
         for (path, contracts) in files.iter() {
@@ -91,20 +116,95 @@ impl Output {
                             println!("{}", ir_optimized);
                         }
 
-                        let mut lexer = Lexer::new(ir_optimized.clone());
-                        let object = Object::parse(&mut lexer, None).map_err(|error| {
-                            anyhow::anyhow!("Contract `{}` parsing error: {:?}", full_path, error)
-                        })?;
+                        let mut object = match Object::parse_recovering(ir_optimized.clone(), None)
+                        {
+                            (Some(object), errors) if errors.is_empty() => object,
+                            (_, errors) => {
+                                return Err(Project::diagnostics_error(
+                                    format!("Contract `{}` parsing errors", full_path),
+                                    errors.into_iter().map(|error| error.to_string()).collect(),
+                                ))
+                            }
+                        };
+                        Project::prepare_yul_object(
+                            &mut object,
+                            full_path.as_str(),
+                            &source_map,
+                            optimizer,
+                            stub_mode,
+                            evm_version,
+                            dump_flags,
+                        )?;
 
                         ProjectContractSource::new_yul(ir_optimized, object)
                     }
                     SolcPipeline::EVM => {
-                        let assembly =
+                        let mut assembly =
                             match contract.evm.as_ref().and_then(|evm| evm.assembly.as_ref()) {
                                 Some(assembly) => assembly.to_owned(),
                                 None => continue,
                             };
 
+                        let unsupported = assembly.unsupported_instructions(evm_version);
+                        if !unsupported.is_empty() {
+                            anyhow::bail!(
+                                "{}",
+                                unsupported
+                                    .into_iter()
+                                    .map(|instruction| {
+                                        let name = instruction.name.to_string();
+                                        let message = match instruction.name.evm_version() {
+                                            Some(required) => format!(
+                                                "Unsupported opcode `{}` in contract `{}`, which requires the EVM version `{}` or newer, but the target is `{}`",
+                                                name.trim_end(),
+                                                full_path,
+                                                required,
+                                                evm_version
+                                            ),
+                                            None => format!(
+                                                "Unsupported opcode `{}` in contract `{}`",
+                                                name.trim_end(),
+                                                full_path
+                                            ),
+                                        };
+                                        source_map.annotate_source(
+                                            &source_paths,
+                                            instruction.source_location(),
+                                            message,
+                                        )
+                                    })
+                                    .collect::<Vec<String>>()
+                                    .join("\n")
+                            );
+                        }
+
+                        if let StubMode::Strict = stub_mode {
+                            let stubs = assembly.stubbed_instructions();
+                            if !stubs.is_empty() {
+                                anyhow::bail!(
+                                    "Contract `{}` strict mode errors:\n{}",
+                                    full_path,
+                                    stubs
+                                        .into_iter()
+                                        .map(|(part, index, instruction)| source_map.annotate_source(
+                                            &source_paths,
+                                            instruction.source_location(),
+                                            format!(
+                                                "The `{}` instruction {} of the {} code has no zkEVM equivalent and is compiled to a stub",
+                                                instruction.name.to_string().trim_end(),
+                                                index,
+                                                part
+                                            )
+                                        ))
+                                        .collect::<Vec<String>>()
+                                        .join("\n")
+                                );
+                            }
+                        }
+                        if let StubMode::Trap = stub_mode {
+                            assembly.trap_stubbed_instructions();
+                        }
+
                         ProjectContractSource::new_evm(full_path.clone(), assembly)
                     }
                 };
@@ -115,7 +215,12 @@ impl Output {
             }
         }
 
-        Ok(Project::new(version, project_contracts, libraries))
+        Ok(Project::new(
+            version,
+            project_contracts,
+            libraries,
+            source_map,
+        ))
     }
 
     ///
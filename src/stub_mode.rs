@@ -0,0 +1,33 @@
+//!
+//! The stubbed operations mode.
+//!
+
+///
+/// The treatment of the EVM operations which have no zkEVM equivalent, and are compiled to
+/// constants or no-ops.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StubMode {
+    /// The stubbed operations are compiled silently.
+    Silent,
+    /// The stubbed operations are rejected at compile time.
+    Strict,
+    /// The stubbed operations are compiled to reverts identifying them.
+    Trap,
+}
+
+impl StubMode {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(strict: bool, trap: bool) -> anyhow::Result<Self> {
+        match (strict, trap) {
+            (true, true) => {
+                anyhow::bail!("The strict and trap modes of the stubbed operations are exclusive")
+            }
+            (true, false) => Ok(Self::Strict),
+            (false, true) => Ok(Self::Trap),
+            (false, false) => Ok(Self::Silent),
+        }
+    }
+}
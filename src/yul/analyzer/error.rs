@@ -0,0 +1,203 @@
+//!
+//! The Yul semantic analyzer error.
+//!
+
+use std::fmt;
+
+use crate::evm_version::EVMVersion;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::r#type::Type;
+
+///
+/// The Yul semantic analyzer error.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The variable is not declared in any visible scope.
+    UndeclaredVariable {
+        /// The variable usage location.
+        location: Location,
+        /// The variable name.
+        name: String,
+    },
+    /// The function is neither a builtin nor declared in any visible scope.
+    UndeclaredFunction {
+        /// The function call location.
+        location: Location,
+        /// The function name.
+        name: String,
+    },
+    /// The identifier is already declared in a visible scope, or is a builtin function name.
+    DuplicateDeclaration {
+        /// The repeated declaration location.
+        location: Location,
+        /// The identifier name.
+        name: String,
+    },
+    /// The function is called with a wrong number of arguments.
+    ArgumentCount {
+        /// The function call location.
+        location: Location,
+        /// The function name.
+        name: String,
+        /// The number of the function parameters.
+        expected: usize,
+        /// The number of the passed arguments.
+        found: usize,
+    },
+    /// The builtin function is introduced by an EVM version newer than the target one.
+    UnavailableBuiltin {
+        /// The function call location.
+        location: Location,
+        /// The function name.
+        name: String,
+        /// The EVM version introducing the function.
+        required: EVMVersion,
+        /// The target EVM version.
+        target: EVMVersion,
+    },
+    /// The expression returns a wrong number of values for its context.
+    ValueCount {
+        /// The expression location.
+        location: Location,
+        /// The number of values expected by the context.
+        expected: usize,
+        /// The number of values returned by the expression.
+        found: usize,
+    },
+    /// The value type is not implicitly convertible to the type expected by the context.
+    TypeMismatch {
+        /// The expression location.
+        location: Location,
+        /// The type expected by the context.
+        expected: Type,
+        /// The type of the expression value.
+        found: Type,
+    },
+    /// The type is not supported by the compiler.
+    UnsupportedType {
+        /// The type usage location.
+        location: Location,
+        /// The unsupported type.
+        r#type: Type,
+    },
+    /// The literal value is not representable by its type.
+    LiteralOutOfRange {
+        /// The literal location.
+        location: Location,
+        /// The literal source code.
+        literal: String,
+        /// The literal type.
+        r#type: Type,
+    },
+    /// The `break`, `continue`, or `leave` statement is used outside of its valid context.
+    MisplacedStatement {
+        /// The statement location.
+        location: Location,
+        /// The statement keyword.
+        keyword: &'static str,
+        /// The description of the valid context.
+        context: &'static str,
+    },
+}
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::UndeclaredVariable { location, .. } => location.clone(),
+            Self::UndeclaredFunction { location, .. } => location.clone(),
+            Self::DuplicateDeclaration { location, .. } => location.clone(),
+            Self::ArgumentCount { location, .. } => location.clone(),
+            Self::UnavailableBuiltin { location, .. } => location.clone(),
+            Self::ValueCount { location, .. } => location.clone(),
+            Self::TypeMismatch { location, .. } => location.clone(),
+            Self::UnsupportedType { location, .. } => location.clone(),
+            Self::LiteralOutOfRange { location, .. } => location.clone(),
+            Self::MisplacedStatement { location, .. } => location.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UndeclaredVariable { location, name } => {
+                write!(f, "{}: Undeclared variable `{}`", location, name)
+            }
+            Self::UndeclaredFunction { location, name } => {
+                write!(f, "{}: Undeclared function `{}`", location, name)
+            }
+            Self::DuplicateDeclaration { location, name } => {
+                write!(
+                    f,
+                    "{}: Identifier `{}` is already declared or reserved",
+                    location, name
+                )
+            }
+            Self::ArgumentCount {
+                location,
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: Function `{}` expects {} arguments, found {}",
+                location, name, expected, found
+            ),
+            Self::UnavailableBuiltin {
+                location,
+                name,
+                required,
+                target,
+            } => write!(
+                f,
+                "{}: Function `{}` requires the EVM version `{}` or newer, but the target is `{}`",
+                location, name, required, target
+            ),
+            Self::ValueCount {
+                location,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: Expected {} values, but the expression returns {}",
+                location, expected, found
+            ),
+            Self::TypeMismatch {
+                location,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: Expected a value of type `{}`, found `{}`",
+                location, expected, found
+            ),
+            Self::UnsupportedType { location, r#type } => {
+                write!(f, "{}: Type `{}` is not supported", location, r#type)
+            }
+            Self::LiteralOutOfRange {
+                location,
+                literal,
+                r#type,
+            } => write!(
+                f,
+                "{}: Literal `{}` is out of range of type `{}`",
+                location, literal, r#type
+            ),
+            Self::MisplacedStatement {
+                location,
+                keyword,
+                context,
+            } => write!(
+                f,
+                "{}: Statement `{}` is only allowed {}",
+                location, keyword, context
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
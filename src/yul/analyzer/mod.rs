@@ -0,0 +1,503 @@
+//!
+//! The Yul semantic analyzer.
+//!
+
+pub mod error;
+pub mod scope;
+
+use crate::evm_version::EVMVersion;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::r#type::Type;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor_mut::VisitorMut;
+
+use self::error::Error;
+use self::scope::Scope;
+
+///
+/// The Yul semantic analyzer.
+///
+/// Resolves the variables and functions in their lexical scopes, checks the function arities,
+/// the numbers and types of values returned by the expressions, the literal ranges, and the
+/// placement of the `break`, `continue`, and `leave` statements, and that the builtins are
+/// available in the target EVM version.
+///
+/// The untyped variables, arguments and literals are `uint256`, and so are the builtin function
+/// arguments and return values. The values can be implicitly converted to the wider types only,
+/// and the other conversions require the `to_{type}` builtins. The untyped literals take the
+/// type expected by their context, if they fit it.
+///
+/// The used identifiers are annotated with the types of their variables, which the LLVM IR
+/// generator relies on to extend the narrower values.
+///
+/// Must be run before the LLVM IR generation, which relies on the code being valid.
+///
+#[derive(Debug, Default)]
+pub struct Analyzer {
+    /// The target EVM version.
+    evm_version: EVMVersion,
+    /// The lexical scope stack, from the outermost to the innermost one.
+    scopes: Vec<Scope>,
+    /// Whether the current statement is inside a loop body.
+    is_in_loop: bool,
+    /// Whether the current statement is inside a function body.
+    is_in_function: bool,
+    /// The errors found so far.
+    errors: Vec<Error>,
+}
+
+impl Analyzer {
+    ///
+    /// Analyzes the object with its inner objects, returning all errors found in the source
+    /// code order.
+    ///
+    pub fn analyze(object: &mut Object, evm_version: EVMVersion) -> Vec<Error> {
+        let mut analyzer = Self {
+            evm_version,
+            ..Self::default()
+        };
+        analyzer.visit_object(object);
+
+        let mut errors = analyzer.errors;
+        errors.sort_by_key(|error| {
+            let location = error.location();
+            (location.line, location.column)
+        });
+        errors
+    }
+
+    ///
+    /// Checks whether the `name` is a builtin function, or is declared in any enclosing scope,
+    /// including the ones hidden by the function boundaries, since shadowing is not allowed.
+    ///
+    /// The `to_{type}` conversions are not reserved, as they are not solc builtins, so the
+    /// user-defined functions of the same names shadow them.
+    ///
+    fn is_declared(&self, name: &str) -> bool {
+        let is_builtin = !matches!(Name::from(name), Name::UserDefined(_) | Name::Conversion(_));
+        is_builtin || self.scopes.iter().any(|scope| scope.contains(name))
+    }
+
+    ///
+    /// Returns the type of the variable `name`, if it is visible from the current scope.
+    ///
+    fn resolve_variable(&self, name: &str) -> Option<Type> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(r#type) = scope.variables.get(name) {
+                return Some(r#type.to_owned());
+            }
+            if scope.is_function {
+                break;
+            }
+        }
+        None
+    }
+
+    ///
+    /// Returns the argument and return value types of the function `name`, if it is visible
+    /// from the current scope.
+    ///
+    fn resolve_function(&self, name: &str) -> Option<(Vec<Type>, Vec<Type>)> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.functions.get(name).cloned())
+    }
+
+    ///
+    /// Declares the variable in the innermost scope.
+    ///
+    fn declare_variable(&mut self, identifier: &Identifier) {
+        let r#type = identifier.yul_type.to_owned().unwrap_or_default();
+        if !r#type.is_supported() {
+            self.errors.push(Error::UnsupportedType {
+                location: identifier.location.clone(),
+                r#type: r#type.to_owned(),
+            });
+        }
+
+        if self.is_declared(identifier.name.as_str()) {
+            self.errors.push(Error::DuplicateDeclaration {
+                location: identifier.location.clone(),
+                name: identifier.name.to_owned(),
+            });
+            return;
+        }
+
+        self.scopes
+            .last_mut()
+            .expect("Always exists")
+            .variables
+            .insert(identifier.name.to_owned(), r#type);
+    }
+
+    ///
+    /// Declares the functions defined in the `block` in the innermost scope, as they are visible
+    /// in the whole block, including the statements preceding the definitions.
+    ///
+    fn hoist_functions(&mut self, block: &Block) {
+        for statement in block.statements.iter() {
+            if let Statement::FunctionDefinition(definition) = statement {
+                if self.is_declared(definition.name.as_str()) {
+                    self.errors.push(Error::DuplicateDeclaration {
+                        location: definition.location.clone(),
+                        name: definition.name.to_owned(),
+                    });
+                    continue;
+                }
+
+                let types = |identifiers: &[Identifier]| -> Vec<Type> {
+                    identifiers
+                        .iter()
+                        .map(|identifier| identifier.yul_type.to_owned().unwrap_or_default())
+                        .collect()
+                };
+                self.scopes
+                    .last_mut()
+                    .expect("Always exists")
+                    .functions
+                    .insert(
+                        definition.name.to_owned(),
+                        (
+                            types(definition.arguments.as_slice()),
+                            types(definition.result.as_slice()),
+                        ),
+                    );
+            }
+        }
+    }
+
+    ///
+    /// Checks that the `expression` returns the values of the `expected` types.
+    ///
+    fn check_expression(&mut self, expression: &mut Expression, expected: &[Type]) {
+        if let [r#type] = expected {
+            self.check_typed_value(expression, r#type);
+            return;
+        }
+
+        let location = expression.location();
+        if let Some(found) = self.expression_types(expression, None) {
+            if found.len() != expected.len() {
+                self.errors.push(Error::ValueCount {
+                    location,
+                    expected: expected.len(),
+                    found: found.len(),
+                });
+                return;
+            }
+
+            for (found, expected) in found.into_iter().zip(expected.iter()) {
+                self.check_conversion(location.clone(), found, expected);
+            }
+        }
+    }
+
+    ///
+    /// Checks that the `expression` returns a single value of the `expected` type.
+    ///
+    fn check_typed_value(&mut self, expression: &mut Expression, expected: &Type) {
+        let location = expression.location();
+        if let Some(found) = self.check_value(expression, Some(expected)) {
+            self.check_conversion(location, found, expected);
+        }
+    }
+
+    ///
+    /// Checks that the `expression` returns a single value, and returns its type.
+    ///
+    /// Returns `None` if the type is unknown due to an error.
+    ///
+    fn check_value(&mut self, expression: &mut Expression, hint: Option<&Type>) -> Option<Type> {
+        let location = expression.location();
+        let mut types = self.expression_types(expression, hint)?;
+        if types.len() != 1 {
+            self.errors.push(Error::ValueCount {
+                location,
+                expected: 1,
+                found: types.len(),
+            });
+            return None;
+        }
+        Some(types.remove(0))
+    }
+
+    ///
+    /// Checks that the value of the `found` type can be used where the `expected` one is.
+    ///
+    fn check_conversion(&mut self, location: Location, found: Type, expected: &Type) {
+        if !found.is_implicitly_convertible_to(expected) {
+            self.errors.push(Error::TypeMismatch {
+                location,
+                expected: expected.to_owned(),
+                found,
+            });
+        }
+    }
+
+    ///
+    /// Checks the `literal` and returns its type.
+    ///
+    /// The untyped literals take the `hint` type, if it is specified.
+    ///
+    fn check_literal(&mut self, literal: &Literal, hint: Option<&Type>) -> Type {
+        let r#type = match literal.yul_type {
+            Some(ref r#type) if !r#type.is_supported() => {
+                self.errors.push(Error::UnsupportedType {
+                    location: literal.location.clone(),
+                    r#type: r#type.to_owned(),
+                });
+                return r#type.to_owned();
+            }
+            Some(ref r#type) => r#type.to_owned(),
+            None => hint.cloned().unwrap_or_default(),
+        };
+
+        if r#type.is_supported() && !literal.fits(&r#type) {
+            self.errors.push(Error::LiteralOutOfRange {
+                location: literal.location.clone(),
+                literal: literal.to_string(),
+                r#type: r#type.to_owned(),
+            });
+        }
+        r#type
+    }
+
+    ///
+    /// Checks the `expression` and returns the types of the values it returns.
+    ///
+    /// The used identifiers are annotated with the types of their variables.
+    ///
+    /// Returns `None` if the types are unknown due to an error.
+    ///
+    fn expression_types(
+        &mut self,
+        expression: &mut Expression,
+        hint: Option<&Type>,
+    ) -> Option<Vec<Type>> {
+        match expression {
+            Expression::Literal(literal) => Some(vec![self.check_literal(literal, hint)]),
+            Expression::Identifier(identifier) => {
+                match self.resolve_variable(identifier.name.as_str()) {
+                    Some(r#type) => {
+                        identifier.yul_type = Some(r#type.clone());
+                        Some(vec![r#type])
+                    }
+                    None => {
+                        self.errors.push(Error::UndeclaredVariable {
+                            location: identifier.location.clone(),
+                            name: identifier.name.to_owned(),
+                        });
+                        None
+                    }
+                }
+            }
+            Expression::FunctionCall(call) => {
+                let name = call.name.to_string();
+                let signature = match call.name {
+                    Name::UserDefined(_) => {
+                        self.resolve_function(name.as_str())
+                            .map(|(input_types, output_types)| {
+                                (input_types.into_iter().map(Some).collect(), output_types)
+                            })
+                    }
+                    Name::Conversion(ref r#type) => {
+                        if !r#type.is_supported() {
+                            self.errors.push(Error::UnsupportedType {
+                                location: call.location.clone(),
+                                r#type: r#type.to_owned(),
+                            });
+                        }
+                        Some((vec![None], vec![r#type.to_owned()]))
+                    }
+                    ref name => {
+                        if let Some(required) = name.evm_version() {
+                            if self.evm_version < required {
+                                self.errors.push(Error::UnavailableBuiltin {
+                                    location: call.location.clone(),
+                                    name: name.to_string(),
+                                    required,
+                                    target: self.evm_version,
+                                });
+                            }
+                        }
+                        name.arity().map(|(input_size, output_size)| {
+                            (
+                                vec![Some(Type::default()); input_size],
+                                vec![Type::default(); output_size],
+                            )
+                        })
+                    }
+                };
+
+                let (input_types, output_types): (Vec<Option<Type>>, Vec<Type>) = match signature {
+                    Some(signature) => signature,
+                    None => {
+                        for argument in call.arguments.iter_mut() {
+                            self.check_value(argument, None);
+                        }
+                        self.errors.push(Error::UndeclaredFunction {
+                            location: call.location.clone(),
+                            name,
+                        });
+                        return None;
+                    }
+                };
+
+                for (index, argument) in call.arguments.iter_mut().enumerate() {
+                    match input_types.get(index) {
+                        Some(Some(r#type)) => self.check_typed_value(argument, r#type),
+                        _ => {
+                            self.check_value(argument, None);
+                        }
+                    }
+                }
+                if call.arguments.len() != input_types.len() {
+                    self.errors.push(Error::ArgumentCount {
+                        location: call.location.clone(),
+                        name,
+                        expected: input_types.len(),
+                        found: call.arguments.len(),
+                    });
+                }
+
+                Some(output_types)
+            }
+        }
+    }
+}
+
+impl VisitorMut for Analyzer {
+    fn visit_block(&mut self, block: &mut Block) {
+        self.scopes.push(Scope::default());
+        self.hoist_functions(block);
+        self.walk_block(block);
+        self.scopes.pop();
+    }
+
+    fn visit_statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Expression(expression) => self.check_expression(expression, &[]),
+            Statement::Continue(location) if !self.is_in_loop => {
+                self.errors.push(Error::MisplacedStatement {
+                    location: location.clone(),
+                    keyword: "continue",
+                    context: "in a `for` loop body",
+                })
+            }
+            Statement::Break(location) if !self.is_in_loop => {
+                self.errors.push(Error::MisplacedStatement {
+                    location: location.clone(),
+                    keyword: "break",
+                    context: "in a `for` loop body",
+                })
+            }
+            Statement::Leave(location) if !self.is_in_function => {
+                self.errors.push(Error::MisplacedStatement {
+                    location: location.clone(),
+                    keyword: "leave",
+                    context: "in a function body",
+                })
+            }
+            statement => self.walk_statement(statement),
+        }
+    }
+
+    fn visit_function_definition(&mut self, definition: &mut FunctionDefinition) {
+        let is_in_loop = std::mem::replace(&mut self.is_in_loop, false);
+        let is_in_function = std::mem::replace(&mut self.is_in_function, true);
+
+        self.scopes.push(Scope::new_function());
+        for identifier in definition.arguments.iter().chain(definition.result.iter()) {
+            self.declare_variable(identifier);
+        }
+        self.visit_block(&mut definition.body);
+        self.scopes.pop();
+
+        self.is_in_loop = is_in_loop;
+        self.is_in_function = is_in_function;
+    }
+
+    fn visit_variable_declaration(&mut self, declaration: &mut VariableDeclaration) {
+        if let Some(ref mut expression) = declaration.expression {
+            let types: Vec<Type> = declaration
+                .bindings
+                .iter()
+                .map(|identifier| identifier.yul_type.to_owned().unwrap_or_default())
+                .collect();
+            self.check_expression(expression, types.as_slice());
+        }
+        for identifier in declaration.bindings.iter() {
+            self.declare_variable(identifier);
+        }
+    }
+
+    fn visit_assignment(&mut self, assignment: &mut Assignment) {
+        let mut types = Vec::with_capacity(assignment.bindings.len());
+        for identifier in assignment.bindings.iter() {
+            match self.resolve_variable(identifier.name.as_str()) {
+                Some(r#type) => types.push(r#type),
+                None => self.errors.push(Error::UndeclaredVariable {
+                    location: identifier.location.clone(),
+                    name: identifier.name.to_owned(),
+                }),
+            }
+        }
+
+        if types.len() == assignment.bindings.len() {
+            self.check_expression(&mut assignment.initializer, types.as_slice());
+        } else {
+            self.expression_types(&mut assignment.initializer, None);
+        }
+    }
+
+    fn visit_if_conditional(&mut self, conditional: &mut IfConditional) {
+        self.check_value(&mut conditional.condition, None);
+        self.visit_block(&mut conditional.block);
+    }
+
+    fn visit_switch(&mut self, switch: &mut Switch) {
+        let r#type = self.check_value(&mut switch.expression, None);
+        for case in switch.cases.iter_mut() {
+            let found = self.check_literal(&case.literal, r#type.as_ref());
+            if let Some(ref r#type) = r#type {
+                self.check_conversion(case.literal.location.clone(), found, r#type);
+            }
+            self.visit_block(&mut case.block);
+        }
+        if let Some(ref mut default) = switch.default {
+            self.visit_block(default);
+        }
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &mut ForLoop) {
+        let is_in_loop = std::mem::replace(&mut self.is_in_loop, false);
+
+        self.scopes.push(Scope::default());
+        self.hoist_functions(&for_loop.initializer);
+        self.walk_block(&mut for_loop.initializer);
+        self.check_value(&mut for_loop.condition, None);
+        self.visit_block(&mut for_loop.finalizer);
+        self.is_in_loop = true;
+        self.visit_block(&mut for_loop.body);
+        self.scopes.pop();
+
+        self.is_in_loop = is_in_loop;
+    }
+
+    fn visit_expression(&mut self, expression: &mut Expression) {
+        self.check_value(expression, None);
+    }
+}
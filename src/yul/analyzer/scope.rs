@@ -0,0 +1,39 @@
+//!
+//! The Yul lexical scope.
+//!
+
+use std::collections::HashMap;
+
+use crate::yul::parser::r#type::Type;
+
+///
+/// The Yul lexical scope.
+///
+#[derive(Debug, Default)]
+pub struct Scope {
+    /// The variables declared in the scope, with their types.
+    pub variables: HashMap<String, Type>,
+    /// The functions declared in the scope, with their argument and return value types.
+    pub functions: HashMap<String, (Vec<Type>, Vec<Type>)>,
+    /// Whether the scope is a function body, which hides the variables of the outer scopes.
+    pub is_function: bool,
+}
+
+impl Scope {
+    ///
+    /// A shortcut constructor for the function arguments and return values scope.
+    ///
+    pub fn new_function() -> Self {
+        Self {
+            is_function: true,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Checks whether the `name` is declared in the scope as either a variable or a function.
+    ///
+    pub fn contains(&self, name: &str) -> bool {
+        self.variables.contains_key(name) || self.functions.contains_key(name)
+    }
+}
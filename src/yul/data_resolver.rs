@@ -0,0 +1,83 @@
+//!
+//! The Yul data section resolver.
+//!
+
+use std::collections::HashMap;
+
+use crate::yul::lexer::lexeme::literal::string::String as StringLiteral;
+use crate::yul::lexer::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::visitor_mut::VisitorMut;
+
+///
+/// The Yul data section resolver.
+///
+/// The `datasize` and `dataoffset` builtins refer to either the child objects or the data
+/// sections of the object whose code they are called in. The resolver attaches the contents of
+/// the referenced data sections to the calls, so they can be lowered to constants, whereas the
+/// child object references are resolved by the LLVM IR generator as dependencies.
+///
+/// The LLVM IR generator recognizes the runtime code object by the solc `_deployed` suffix, so
+/// the references to a runtime code object named otherwise are renamed accordingly.
+///
+#[derive(Debug, Default)]
+pub struct DataResolver {
+    /// The data sections of the current object, by their identifiers.
+    data: HashMap<String, Vec<u8>>,
+    /// The runtime code object identifier of the current object, and its solc name.
+    runtime: Option<(String, String)>,
+}
+
+impl DataResolver {
+    ///
+    /// Resolves the data section references in the object tree.
+    ///
+    pub fn resolve(object: &mut Object) {
+        Self::default().visit_object(object);
+    }
+}
+
+impl VisitorMut for DataResolver {
+    fn visit_object(&mut self, object: &mut Object) {
+        let data = object
+            .data
+            .iter()
+            .map(|data| (data.identifier.to_owned(), data.value.bytes.to_owned()))
+            .collect();
+        let runtime = object.object.as_ref().map(|runtime| {
+            (
+                runtime.identifier.to_owned(),
+                format!("{}{}", object.identifier, Object::RUNTIME_SUFFIX),
+            )
+        });
+        let parent_data = std::mem::replace(&mut self.data, data);
+        let parent_runtime = std::mem::replace(&mut self.runtime, runtime);
+        self.walk_object(object);
+        self.data = parent_data;
+        self.runtime = parent_runtime;
+    }
+
+    fn visit_function_call(&mut self, call: &mut FunctionCall) {
+        if let Name::DataSize | Name::DataOffset = call.name {
+            if let Some(Expression::Literal(literal)) = call.arguments.first_mut() {
+                if let LexicalLiteral::String(ref mut identifier) = literal.inner {
+                    call.data = self.data.get(identifier.inner.as_str()).cloned();
+
+                    if let Some((ref runtime, ref solc_name)) = self.runtime {
+                        if identifier.inner == *runtime && call.data.is_none() {
+                            *identifier = StringLiteral::new(
+                                solc_name.to_owned(),
+                                false,
+                                solc_name.as_bytes().to_vec(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        self.walk_function_call(call);
+    }
+}
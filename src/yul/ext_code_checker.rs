@@ -0,0 +1,50 @@
+//!
+//! The Yul external code builtin checker.
+//!
+
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::visitor::Visitor;
+
+///
+/// The Yul external code builtin checker.
+///
+/// Finds the calls to the builtins reading the code of other accounts, so the contracts relying
+/// on the EVM behavior of the builtins can be warned about.
+///
+/// Only the deploy and runtime code of the object are checked, as the dependency objects are
+/// compiled, and therefore checked, as separate contracts.
+///
+#[derive(Debug, Default)]
+pub struct ExtCodeChecker {
+    /// Whether an external code builtin call has been found.
+    is_found: bool,
+}
+
+impl ExtCodeChecker {
+    ///
+    /// Checks whether the deploy or runtime code of the `object` calls the external code
+    /// builtins.
+    ///
+    pub fn check(object: &Object) -> bool {
+        let mut checker = Self::default();
+        checker.visit_object(object);
+        checker.is_found
+    }
+}
+
+impl Visitor for ExtCodeChecker {
+    fn visit_object(&mut self, object: &Object) {
+        self.visit_code(&object.code);
+        if let Some(ref runtime) = object.object {
+            self.visit_object(runtime);
+        }
+    }
+
+    fn visit_function_call(&mut self, call: &FunctionCall) {
+        if call.name.is_ext_code() {
+            self.is_found = true;
+        }
+        self.walk_function_call(call);
+    }
+}
@@ -0,0 +1,75 @@
+//!
+//! The Yul lexer error.
+//!
+
+use std::fmt;
+
+use crate::yul::lexer::token::location::Location;
+
+///
+/// The Yul lexer error.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The character sequence cannot be recognized as any lexeme.
+    InvalidLexeme {
+        /// The invalid sequence location.
+        location: Location,
+        /// The invalid sequence.
+        sequence: String,
+    },
+    /// The string literal is not closed on its line.
+    UnterminatedString {
+        /// The string literal location.
+        location: Location,
+    },
+    /// The string literal contains an unknown or malformed escape sequence.
+    InvalidEscapeSequence {
+        /// The escape sequence location.
+        location: Location,
+        /// The escape sequence.
+        sequence: String,
+    },
+    /// The hexadecimal string literal contains an odd number of digits or other characters.
+    InvalidHexString {
+        /// The invalid part location.
+        location: Location,
+        /// The invalid part.
+        sequence: String,
+    },
+    /// The integer literal does not fit into 256 bits.
+    IntegerOutOfRange {
+        /// The integer literal location.
+        location: Location,
+        /// The integer literal.
+        literal: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLexeme { location, sequence } => {
+                write!(f, "{}: Invalid lexeme `{}`", location, sequence)
+            }
+            Self::UnterminatedString { location } => {
+                write!(f, "{}: Unterminated string literal", location)
+            }
+            Self::InvalidEscapeSequence { location, sequence } => {
+                write!(f, "{}: Invalid escape sequence `{}`", location, sequence)
+            }
+            Self::InvalidHexString { location, sequence } => write!(
+                f,
+                "{}: Invalid hexadecimal string part `{}`, expected pairs of hexadecimal digits",
+                location, sequence
+            ),
+            Self::IntegerOutOfRange { location, literal } => write!(
+                f,
+                "{}: Integer literal `{}` does not fit into 256 bits",
+                location, literal
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
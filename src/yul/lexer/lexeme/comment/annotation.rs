@@ -0,0 +1,140 @@
+//!
+//! The comment annotation.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::yul::lexer::token::source_location::SourceLocation;
+
+///
+/// The comment annotation.
+///
+/// solc annotates its Yul output with the NatSpec-like tags in comments, which map the Yul code
+/// back to the Solidity sources:
+///
+/// ```yul
+/// /// @use-src 0:"ERC20.sol", 1:"IERC20.sol"
+/// object "ERC20_412" {
+///     code {
+///         /// @src 0:57:2620  "contract ERC20 {..."
+///         mstore(64, memoryguard(0x80))
+///     }
+/// }
+/// ```
+///
+/// The other tags, like `@ast-id`, are ignored.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Annotation {
+    /// The `@use-src` annotation with the source file paths by their indexes.
+    UseSource(BTreeMap<usize, String>),
+    /// The `@src` annotation with the Solidity source location, which is `None` if unknown.
+    Source(Option<SourceLocation>),
+}
+
+impl Annotation {
+    /// The `@use-src` tag.
+    pub const TAG_USE_SOURCE: &'static str = "@use-src";
+    /// The `@src` tag.
+    pub const TAG_SOURCE: &'static str = "@src";
+
+    ///
+    /// Parses the annotations in the comment text.
+    ///
+    /// The malformed annotations are ignored, since comments do not affect the code.
+    ///
+    pub fn parse_all(comment: &str) -> Vec<Self> {
+        let mut annotations = Vec::new();
+
+        let mut input = comment;
+        while let Some(position) = input.find(['@', '"']) {
+            input = &input[position..];
+            if input.starts_with('"') {
+                input = &input[Self::quoted_length(input)..];
+                continue;
+            }
+
+            let tag_length = input
+                .find(|character: char| character.is_whitespace())
+                .unwrap_or(input.len());
+            let (tag, rest) = input.split_at(tag_length);
+            input = rest.trim_start();
+            match tag {
+                Self::TAG_USE_SOURCE => {
+                    let (length, sources) = Self::parse_use_source(input);
+                    input = &input[length..];
+                    if let Some(sources) = sources {
+                        annotations.push(Self::UseSource(sources));
+                    }
+                }
+                Self::TAG_SOURCE => {
+                    let length = input
+                        .find(|character: char| character.is_whitespace())
+                        .unwrap_or(input.len());
+                    if let Some(location) = SourceLocation::parse(&input[..length]) {
+                        annotations.push(Self::Source(location));
+                    }
+                    input = &input[length..];
+                }
+                _ => {}
+            }
+        }
+
+        annotations
+    }
+
+    ///
+    /// Parses the comma-separated `index:"path"` list of the `@use-src` annotation.
+    ///
+    /// Returns the length of the list in bytes, and the list if it is well-formed.
+    ///
+    fn parse_use_source(input: &str) -> (usize, Option<BTreeMap<usize, String>>) {
+        let mut sources = BTreeMap::new();
+
+        let mut length = 0;
+        loop {
+            let item = &input[length..];
+            let index_length = item
+                .find(|character: char| !character.is_ascii_digit())
+                .unwrap_or(item.len());
+            let index = match item[..index_length].parse::<usize>() {
+                Ok(index) if item[index_length..].starts_with(":\"") => index,
+                _ => return (length, None),
+            };
+
+            let path = &item[index_length + 1..];
+            let path_length = Self::quoted_length(path);
+            if path_length < 2 || !path[..path_length].ends_with('"') {
+                return (length, None);
+            }
+            sources.insert(index, path[1..path_length - 1].to_owned());
+            length += index_length + 1 + path_length;
+
+            let separator = &input[length..];
+            let trimmed = separator.trim_start();
+            match trimmed.strip_prefix(',') {
+                Some(next) => length += separator.len() - next.trim_start().len(),
+                None => break,
+            }
+        }
+
+        (length, Some(sources))
+    }
+
+    ///
+    /// Returns the length of the quoted string at the start of the `input`, including the
+    /// quotes, or the rest of the `input` if the string is not closed.
+    ///
+    fn quoted_length(input: &str) -> usize {
+        let mut is_escaped = false;
+        for (index, character) in input.char_indices().skip(1) {
+            match character {
+                _ if is_escaped => is_escaped = false,
+                '\\' => is_escaped = true,
+                '"' => return index + 1,
+                _ => {}
+            }
+        }
+        input.len()
+    }
+}
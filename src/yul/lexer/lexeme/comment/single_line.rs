@@ -18,7 +18,9 @@ impl Comment {
     /// Returns the comment's length, including the trimmed whitespace around it.
     ///
     pub fn parse(input: &str) -> usize {
-        let end_position = input.find(Self::END).unwrap_or(input.len());
-        end_position + Self::END.len()
+        input
+            .find(Self::END)
+            .map(|position| position + Self::END.len())
+            .unwrap_or(input.len())
     }
 }
@@ -36,20 +36,32 @@ impl Integer {
         Self::Hexadecimal { inner }
     }
 
+    ///
+    /// Returns the literal value.
+    ///
+    pub fn to_biguint(&self) -> num::BigUint {
+        match self {
+            Self::Decimal { inner } => num::BigUint::parse_bytes(inner.as_bytes(), 10),
+            Self::Hexadecimal { inner } => {
+                num::BigUint::parse_bytes(&inner.as_bytes()["0x".len()..], 16)
+            }
+        }
+        .expect("Always valid")
+    }
+
     ///
     /// Parses the value from the source code slice.
     ///
     pub fn parse(input: &str) -> Option<Self> {
-        let decimal = regex::Regex::new("^[0-9]+$").expect("Regexp is valid");
-        let hexadecimal = regex::Regex::new(r#"^0x[0-9a-fA-F]+$"#).expect("Regexp is valid");
-
-        if decimal.is_match(input) {
-            Some(Self::new_decimal(input.to_owned()))
-        } else if hexadecimal.is_match(input) {
-            Some(Self::new_hexadecimal(input.to_owned()))
-        } else {
-            None
+        if let Some(digits) = input.strip_prefix("0x") {
+            if !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+                return Some(Self::new_hexadecimal(input.to_owned()));
+            }
+        } else if !input.is_empty() && input.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Some(Self::new_decimal(input.to_owned()));
         }
+
+        None
     }
 }
 
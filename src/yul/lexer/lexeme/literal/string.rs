@@ -4,64 +4,178 @@
 
 use std::fmt;
 
+use crate::yul::lexer::error::Error;
+use crate::yul::lexer::token::location::Location;
+
 ///
 /// The string literal lexeme.
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub struct String {
-    /// The inner string contents.
+    /// The inner string contents, as written in the source code.
     pub inner: std::string::String,
     /// Whether the string is hexadecimal.
     pub is_hexadecimal: bool,
+    /// The string bytes, with the escape sequences and hexadecimal digits decoded.
+    pub bytes: Vec<u8>,
 }
 
 impl String {
     ///
     /// Creates a string literal value.
     ///
-    pub fn new(inner: ::std::string::String, is_hexadecimal: bool) -> Self {
+    pub fn new(inner: ::std::string::String, is_hexadecimal: bool, bytes: Vec<u8>) -> Self {
         Self {
             inner,
             is_hexadecimal,
+            bytes,
         }
     }
 
     ///
     /// Parses the value from the source code slice.
     ///
-    pub fn parse(input: &str) -> Option<(usize, Self)> {
-        let mut length = 0;
-
-        let is_string = input[length..].starts_with('"');
-        let is_hex_string = input[length..].starts_with(r#"hex""#);
-
-        if !is_string && !is_hex_string {
+    /// Returns the length of the literal in bytes, and either the literal or the error found in
+    /// it at the `location`. The length of an invalid literal is returned as well, so the lexer
+    /// can skip it and continue.
+    ///
+    pub fn parse(input: &str, location: Location) -> Option<(usize, Result<Self, Error>)> {
+        let (is_hexadecimal, start) = if input.starts_with('"') {
+            (false, 1)
+        } else if input.starts_with(r#"hex""#) {
+            (true, r#"hex""#.len())
+        } else {
             return None;
-        }
+        };
 
-        if is_string {
-            length += 1;
+        let bytes = input.as_bytes();
+        let mut length = start;
+        while length < bytes.len() && bytes[length] != b'"' {
+            match bytes[length] {
+                b'\n' | b'\r' => break,
+                b'\\' if !is_hexadecimal && length + 1 < bytes.len() => length += 2,
+                _ => length += 1,
+            }
         }
-        if is_hex_string {
-            length += r#"hex""#.len();
+        if length >= bytes.len() || bytes[length] != b'"' {
+            return Some((length, Err(Error::UnterminatedString { location })));
         }
 
-        let mut string = std::string::String::new();
-        while !input[length..].starts_with('"') {
-            string.push(input.chars().nth(length).expect("Always exists"));
-            length += 1;
+        let inner = &input[start..length];
+        let result = if is_hexadecimal {
+            Self::decode_hexadecimal(inner)
+        } else {
+            Self::decode_escaped(inner)
+        };
+        let result = result
+            .map(|bytes| Self::new(inner.to_owned(), is_hexadecimal, bytes))
+            .map_err(|(offset, sequence)| {
+                let mut location = location;
+                location.column += input[..start + offset].chars().count();
+                if is_hexadecimal {
+                    Error::InvalidHexString { location, sequence }
+                } else {
+                    Error::InvalidEscapeSequence { location, sequence }
+                }
+            });
+
+        Some((length + 1, result))
+    }
+
+    ///
+    /// Decodes the escape sequences of a regular string.
+    ///
+    /// The escape sequences are `\\`, `\"`, `\'`, `\n`, `\r`, `\t`, `\xNN`, which is the byte
+    /// `NN`, and `\uNNNN`, which is the UTF-8 encoding of the code point `NNNN`.
+    ///
+    /// Returns the byte offset and the text of the invalid escape sequence on error.
+    ///
+    fn decode_escaped(inner: &str) -> Result<Vec<u8>, (usize, std::string::String)> {
+        let mut bytes = Vec::with_capacity(inner.len());
+
+        let mut index = 0;
+        while let Some(offset) = inner[index..].find('\\') {
+            bytes.extend_from_slice(&inner.as_bytes()[index..index + offset]);
+            index += offset;
+
+            let sequence = &inner[index..];
+            let (length, decoded) = match sequence.as_bytes().get(1) {
+                Some(b'\\') => (2, Some(b'\\')),
+                Some(b'"') => (2, Some(b'"')),
+                Some(b'\'') => (2, Some(b'\'')),
+                Some(b'n') => (2, Some(b'\n')),
+                Some(b'r') => (2, Some(b'\r')),
+                Some(b't') => (2, Some(b'\t')),
+                Some(b'x') => (
+                    4,
+                    Self::parse_hexadecimal(sequence.get(2..4)).map(|code| code as u8),
+                ),
+                Some(b'u') => {
+                    let character =
+                        Self::parse_hexadecimal(sequence.get(2..6)).and_then(char::from_u32);
+                    if let Some(character) = character {
+                        let mut buffer = [0u8; 4];
+                        bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                        index += 6;
+                        continue;
+                    }
+                    (6, None)
+                }
+                _ => (2, None),
+            };
+
+            match decoded {
+                Some(byte) => bytes.push(byte),
+                None => {
+                    let sequence = sequence.chars().take(length).collect();
+                    return Err((index, sequence));
+                }
+            }
+            index += length;
         }
+        bytes.extend_from_slice(&inner.as_bytes()[index..]);
+
+        Ok(bytes)
+    }
+
+    ///
+    /// Decodes the digits of a hexadecimal string.
+    ///
+    /// The string must consist of an even number of hexadecimal digits, optionally separated by
+    /// single underscores between the bytes.
+    ///
+    /// Returns the byte offset and the text of the invalid part on error.
+    ///
+    fn decode_hexadecimal(inner: &str) -> Result<Vec<u8>, (usize, std::string::String)> {
+        let mut bytes = Vec::with_capacity(inner.len() / 2);
 
-        length += 1;
-        let string = string
-            .strip_prefix('"')
-            .and_then(|string| string.strip_suffix('"'))
-            .unwrap_or(string.as_str())
-            .to_owned();
+        let mut index = 0;
+        while index < inner.len() {
+            if index > 0 && inner.as_bytes()[index] == b'_' {
+                index += 1;
+            }
+            match Self::parse_hexadecimal(inner.get(index..index + 2)) {
+                Some(byte) => bytes.push(byte as u8),
+                None => {
+                    let sequence = inner[index..].chars().take(2).collect();
+                    return Err((index, sequence));
+                }
+            }
+            index += 2;
+        }
 
-        let literal = Self::new(string, is_hex_string);
+        Ok(bytes)
+    }
 
-        Some((length, literal))
+    ///
+    /// Parses the hexadecimal `digits`, which must not be empty or contain other characters.
+    ///
+    fn parse_hexadecimal(digits: Option<&str>) -> Option<u32> {
+        let digits = digits?;
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        u32::from_str_radix(digits, 16).ok()
     }
 }
 
@@ -32,12 +32,12 @@ pub enum Lexeme {
 
 impl Lexeme {
     ///
-    /// Checks whether the lexeme is a valid identifier.
+    /// Checks whether the byte may be a part of an identifier, keyword, or number.
     ///
-    pub fn is_identifier(string: &str) -> bool {
-        regex::Regex::new(r"^[a-zA-Z_\$][a-zA-Z0-9_\$\.]*$")
-            .expect("Always valid")
-            .is_match(string)
+    /// The identifiers must not start with a digit, which is checked by the lexer.
+    ///
+    pub fn is_identifier_byte(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'$' | b'.')
     }
 }
 
@@ -29,10 +29,21 @@ pub enum Symbol {
 
 impl Symbol {
     ///
-    /// Returns the regexp used for matching the symbol.
+    /// Parses the symbol at the start of the source code slice, returning its length.
     ///
-    pub fn regexp() -> regex::Regex {
-        regex::Regex::new(r"(\s+)|(:=)|(\->)|[{}(),:]|(/\*)|(\*/)").expect("Regexp is valid")
+    pub fn parse(input: &str) -> Option<(usize, Self)> {
+        let bytes = input.as_bytes();
+        Some(match bytes.first()? {
+            b':' if bytes.get(1) == Some(&b'=') => (2, Self::Assignment),
+            b'-' if bytes.get(1) == Some(&b'>') => (2, Self::Arrow),
+            b'{' => (1, Self::BracketCurlyLeft),
+            b'}' => (1, Self::BracketCurlyRight),
+            b'(' => (1, Self::ParenthesisLeft),
+            b')' => (1, Self::ParenthesisRight),
+            b',' => (1, Self::Comma),
+            b':' => (1, Self::Colon),
+            _ => return None,
+        })
     }
 }
 
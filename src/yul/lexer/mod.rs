@@ -2,8 +2,15 @@
 //! The compiler lexer.
 //!
 
+pub mod error;
 pub mod lexeme;
+pub mod token;
 
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use self::error::Error;
+use self::lexeme::comment::annotation::Annotation;
 use self::lexeme::comment::Comment;
 use self::lexeme::keyword::Keyword;
 use self::lexeme::literal::boolean::Boolean as BooleanLiteral;
@@ -12,19 +19,35 @@ use self::lexeme::literal::string::String as StringLiteral;
 use self::lexeme::literal::Literal;
 use self::lexeme::symbol::Symbol;
 use self::lexeme::Lexeme;
+use self::token::location::Location;
+use self::token::source_location::SourceLocation;
+use self::token::Token;
 
 ///
 /// The compiler lexer.
 ///
+/// A single-pass scanner over the source code bytes. Besides the `next` and `peek` methods used
+/// by the parser, the lexer is an iterator over the tokens, which stops at the end of file.
+///
+/// The comments are skipped, but their `@use-src` and `@src` annotations are kept track of.
+/// Each token location refers to the Solidity source location of the last `@src` annotation,
+/// until it is reset by the next `@use-src` one, which starts a new object.
+///
 pub struct Lexer {
     /// The input source code.
     input: String,
-    /// The tokenization regular expression.
-    regexp: regex::Regex,
     /// The position in the source code.
     index: usize,
-    /// The peeked lexeme, waiting to be fetched.
-    peeked: Option<Lexeme>,
+    /// The line and column of the current position.
+    location: Location,
+    /// The peeked token, waiting to be fetched.
+    peeked: Option<Token>,
+    /// The errors collected in the error recovery mode, which is disabled if `None`.
+    errors: Option<Vec<anyhow::Error>>,
+    /// The Solidity source location of the last `@src` annotation.
+    source_location: Option<SourceLocation>,
+    /// The source file paths of the last `@use-src` annotation, waiting to be taken by an object.
+    sources: Option<BTreeMap<usize, String>>,
 }
 
 impl Lexer {
@@ -36,80 +59,157 @@ impl Lexer {
 
         Self {
             input,
-            regexp: Symbol::regexp(),
             index: 0,
+            location: Location::default(),
             peeked: None,
+            errors: None,
+            source_location: None,
+            sources: None,
         }
     }
 
     ///
-    /// Advances the lexer, returning the next lexeme.
+    /// A shortcut constructor, which enables the error recovery mode.
+    ///
+    /// In the recovery mode, the parser does not stop at the first syntax error, but records it
+    /// and resynchronizes at the next statement or block boundary.
+    ///
+    /// The source file `path`, if specified, is written to the token locations.
+    ///
+    pub fn new_recovering(input: String, path: Option<&str>) -> Self {
+        let mut lexer = Self::new(input);
+        lexer.errors = Some(Vec::new());
+        lexer.location.path = path.map(Arc::from);
+        lexer
+    }
+
+    ///
+    /// Records the `error` if the recovery mode is enabled, otherwise returns it back.
+    ///
+    /// Repeated errors, e.g. the unexpected end of file reported by each unclosed block,
+    /// are recorded only once.
+    ///
+    pub fn recover(&mut self, error: anyhow::Error) -> anyhow::Result<()> {
+        match self.errors {
+            Some(ref mut errors) => {
+                if errors.last().map(|last| last.to_string()) != Some(error.to_string()) {
+                    errors.push(error);
+                }
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
+    ///
+    /// Takes the errors collected in the recovery mode.
+    ///
+    pub fn take_errors(&mut self) -> Vec<anyhow::Error> {
+        self.errors.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    ///
+    /// Takes the source file paths of the last `@use-src` annotation.
+    ///
+    /// The annotation precedes the object it belongs to, so the object parser takes the paths
+    /// right after its `object` keyword.
+    ///
+    pub fn take_sources(&mut self) -> BTreeMap<usize, String> {
+        self.sources.take().unwrap_or_default()
+    }
+
+    ///
+    /// Advances the lexer, returning the next token.
     ///
     #[allow(clippy::should_implement_trait)]
-    pub fn next(&mut self) -> anyhow::Result<Lexeme> {
+    pub fn next(&mut self) -> anyhow::Result<Token> {
         if let Some(peeked) = self.peeked.take() {
             return Ok(peeked);
         }
 
         loop {
-            if let Some(length) = Comment::parse(&self.input[self.index..]) {
-                self.index += length;
-                continue;
-            }
+            let whitespace = self.input.as_bytes()[self.index..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_whitespace())
+                .count();
+            self.shift(whitespace);
 
-            if let Some((length, literal)) = StringLiteral::parse(&self.input[self.index..]) {
-                self.index += length;
-                let lexeme = Lexeme::Literal(Literal::String(literal));
-                return Ok(lexeme);
+            match Comment::parse(&self.input[self.index..]) {
+                Some(length) => {
+                    self.annotate(length);
+                    self.shift(length);
+                }
+                None => break,
             }
+        }
+
+        let mut location = self.location.clone();
+        location.source = self.source_location;
+        let input = &self.input[self.index..];
+        if input.is_empty() {
+            return Ok(Token::new(location, Lexeme::EndOfFile, 0));
+        }
 
-            let r#match = match self.regexp.find(&self.input[self.index..]) {
-                Some(r#match) => r#match,
-                None => return Ok(Lexeme::EndOfFile),
-            };
-
-            let lexeme = if r#match.start() != 0 {
-                let lexeme = match Keyword::try_from(
-                    &self.input[self.index..self.index + r#match.start()],
-                ) {
-                    Ok(keyword) => match BooleanLiteral::try_from(keyword) {
-                        Ok(literal) => Lexeme::Literal(Literal::Boolean(literal)),
-                        Err(keyword) => Lexeme::Keyword(keyword),
-                    },
-                    Err(string) => {
-                        if let Some(literal) = IntegerLiteral::parse(string.as_str()) {
-                            Lexeme::Literal(Literal::Integer(literal))
-                        } else if Lexeme::is_identifier(string.as_str()) {
-                            Lexeme::Identifier(string)
-                        } else {
-                            anyhow::bail!("Invalid lexeme `{}`", string);
-                        }
-                    }
-                };
-                self.index += r#match.start();
-                lexeme
-            } else if !r#match.as_str().trim().is_empty() {
-                let lexeme = match Symbol::try_from(r#match.as_str()) {
-                    Ok(symbol) => Lexeme::Symbol(symbol),
-                    Err(string) => {
-                        anyhow::bail!("Invalid lexeme `{}`", string);
-                    }
-                };
-                self.index += r#match.as_str().len();
-                lexeme
-            } else {
-                self.index += r#match.as_str().len();
-                continue;
-            };
-
-            return Ok(lexeme);
+        if let Some((length, literal)) = StringLiteral::parse(input, location.clone()) {
+            self.shift(length);
+            return Ok(Token::new(
+                location,
+                Lexeme::Literal(Literal::String(literal?)),
+                length,
+            ));
         }
+
+        if let Some((length, symbol)) = Symbol::parse(input) {
+            self.shift(length);
+            return Ok(Token::new(location, Lexeme::Symbol(symbol), length));
+        }
+
+        let length = input
+            .bytes()
+            .take_while(|byte| Lexeme::is_identifier_byte(*byte))
+            .count();
+        if length == 0 {
+            let length = input.chars().next().map(char::len_utf8).unwrap_or(1);
+            let sequence = input[..length].to_owned();
+            self.shift(length);
+            anyhow::bail!(Error::InvalidLexeme { location, sequence });
+        }
+
+        let word = &input[..length];
+        let lexeme = if word.as_bytes()[0].is_ascii_digit() {
+            match IntegerLiteral::parse(word) {
+                Some(literal)
+                    if literal.to_biguint().bits() > compiler_common::BITLENGTH_FIELD as u64 =>
+                {
+                    let literal = word.to_owned();
+                    self.shift(length);
+                    anyhow::bail!(Error::IntegerOutOfRange { location, literal });
+                }
+                Some(literal) => Lexeme::Literal(Literal::Integer(literal)),
+                None => {
+                    let sequence = word.to_owned();
+                    self.shift(length);
+                    anyhow::bail!(Error::InvalidLexeme { location, sequence });
+                }
+            }
+        } else {
+            match Keyword::try_from(word) {
+                Ok(keyword) => match BooleanLiteral::try_from(keyword) {
+                    Ok(literal) => Lexeme::Literal(Literal::Boolean(literal)),
+                    Err(keyword) => Lexeme::Keyword(keyword),
+                },
+                Err(identifier) => Lexeme::Identifier(identifier),
+            }
+        };
+
+        self.shift(length);
+        Ok(Token::new(location, lexeme, length))
     }
 
     ///
-    /// Peeks the next lexeme without advancing the iterator.
+    /// Peeks the next token without advancing the iterator.
     ///
-    pub fn peek(&mut self) -> anyhow::Result<Lexeme> {
+    pub fn peek(&mut self) -> anyhow::Result<Token> {
         match self.peeked {
             Some(ref peeked) => Ok(peeked.clone()),
             None => {
@@ -119,4 +219,55 @@ impl Lexer {
             }
         }
     }
+
+    ///
+    /// Applies the annotations of the comment of `length` bytes at the current position.
+    ///
+    fn annotate(&mut self, length: usize) {
+        let comment = &self.input[self.index..self.index + length];
+        if !comment.contains('@') {
+            return;
+        }
+
+        for annotation in Annotation::parse_all(comment) {
+            match annotation {
+                Annotation::UseSource(sources) => {
+                    self.sources = Some(sources);
+                    self.source_location = None;
+                }
+                Annotation::Source(location) => self.source_location = location,
+            }
+        }
+    }
+
+    ///
+    /// Advances the position by `length` bytes, updating the line and column.
+    ///
+    /// The column is counted in characters, so the UTF-8 continuation bytes are skipped.
+    ///
+    fn shift(&mut self, length: usize) {
+        for byte in self.input.as_bytes()[self.index..self.index + length].iter() {
+            if *byte == b'\n' {
+                self.location.line += 1;
+                self.location.column = 1;
+            } else if *byte & 0b1100_0000 != 0b1000_0000 {
+                self.location.column += 1;
+            }
+        }
+        self.index += length;
+    }
+}
+
+impl Iterator for Lexer {
+    type Item = anyhow::Result<Token>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match Lexer::next(self) {
+            Ok(Token {
+                lexeme: Lexeme::EndOfFile,
+                ..
+            }) => None,
+            result => Some(result),
+        }
+    }
 }
@@ -0,0 +1,58 @@
+//!
+//! The lexical token location.
+//!
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::yul::lexer::token::source_location::SourceLocation;
+
+///
+/// The lexical token location.
+///
+/// Both the line and the column are counted from 1. The file path is known if the source code
+/// has been read from a file.
+///
+/// If the Yul code is annotated with `@src` comments, the location also refers to the Solidity
+/// code the Yul code is generated from.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    /// The source file path.
+    pub path: Option<Arc<str>>,
+    /// The line number.
+    pub line: usize,
+    /// The column number.
+    pub column: usize,
+    /// The Solidity source code location, if the code is annotated.
+    pub source: Option<SourceLocation>,
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+impl Location {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(line: usize, column: usize) -> Self {
+        Self {
+            path: None,
+            line,
+            column,
+            source: None,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref path) = self.path {
+            write!(f, "{}:", path)?;
+        }
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
@@ -0,0 +1,38 @@
+//!
+//! The lexical token.
+//!
+
+pub mod location;
+pub mod source_location;
+
+use crate::yul::lexer::lexeme::Lexeme;
+
+use self::location::Location;
+
+///
+/// The lexical token.
+///
+/// Wraps a lexeme with its position in the source code.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    /// The token location.
+    pub location: Location,
+    /// The lexeme.
+    pub lexeme: Lexeme,
+    /// The token length in bytes, including the literal quotes and prefixes.
+    pub length: usize,
+}
+
+impl Token {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, lexeme: Lexeme, length: usize) -> Self {
+        Self {
+            location,
+            lexeme,
+            length,
+        }
+    }
+}
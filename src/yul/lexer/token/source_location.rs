@@ -0,0 +1,67 @@
+//!
+//! The Solidity source code location.
+//!
+
+use std::fmt;
+
+///
+/// The Solidity source code location.
+///
+/// Taken from the `@src` annotations in the Yul emitted by solc, which refer to the byte range
+/// of the Solidity code the Yul code is generated from.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourceLocation {
+    /// The source file index, as specified by the `@use-src` annotation.
+    pub file: usize,
+    /// The start byte offset.
+    pub start: usize,
+    /// The length in bytes.
+    pub length: usize,
+}
+
+impl SourceLocation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(file: usize, start: usize, length: usize) -> Self {
+        Self {
+            file,
+            start,
+            length,
+        }
+    }
+
+    ///
+    /// Parses the location in the `file:start:length` format.
+    ///
+    /// Returns `Some(None)` for the unknown location `-1:-1:-1`, and `None` if the input is
+    /// malformed.
+    ///
+    pub fn parse(input: &str) -> Option<Option<Self>> {
+        let parts = input
+            .split(':')
+            .map(str::parse::<isize>)
+            .collect::<Result<Vec<isize>, _>>()
+            .ok()?;
+        let (file, start, length) = match parts.as_slice() {
+            [file, start, length] => (*file, *start, *length),
+            _ => return None,
+        };
+
+        if file < 0 || start < 0 || length < 0 {
+            return Some(None);
+        }
+        Some(Some(Self::new(
+            file as usize,
+            start as usize,
+            length as usize,
+        )))
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.start, self.length)
+    }
+}
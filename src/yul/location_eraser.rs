@@ -0,0 +1,129 @@
+//!
+//! The Yul location eraser.
+//!
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::data::Data;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::case::Case;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor_mut::VisitorMut;
+
+///
+/// The Yul location eraser.
+///
+/// Resets all locations in the object tree, so the trees parsed from the differently formatted
+/// sources can be compared, e.g. an object and the object parsed from its printed source.
+///
+#[derive(Debug, Default)]
+pub struct LocationEraser {}
+
+impl LocationEraser {
+    ///
+    /// Resets the locations in the object tree.
+    ///
+    pub fn erase(object: &mut Object) {
+        Self::default().visit_object(object);
+    }
+
+    ///
+    /// Checks whether the object trees are equal, ignoring their locations.
+    ///
+    pub fn eq(first: &Object, second: &Object) -> bool {
+        let mut first = first.to_owned();
+        Self::erase(&mut first);
+        let mut second = second.to_owned();
+        Self::erase(&mut second);
+        first == second
+    }
+}
+
+impl VisitorMut for LocationEraser {
+    fn visit_object(&mut self, object: &mut Object) {
+        object.location = Location::default();
+        self.walk_object(object);
+    }
+
+    fn visit_data(&mut self, data: &mut Data) {
+        data.location = Location::default();
+    }
+
+    fn visit_code(&mut self, code: &mut Code) {
+        code.location = Location::default();
+        self.walk_code(code);
+    }
+
+    fn visit_block(&mut self, block: &mut Block) {
+        block.location = Location::default();
+        self.walk_block(block);
+    }
+
+    fn visit_statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Continue(location)
+            | Statement::Break(location)
+            | Statement::Leave(location) => *location = Location::default(),
+            _ => {}
+        }
+        self.walk_statement(statement);
+    }
+
+    fn visit_function_definition(&mut self, definition: &mut FunctionDefinition) {
+        definition.location = Location::default();
+        self.walk_function_definition(definition);
+    }
+
+    fn visit_variable_declaration(&mut self, declaration: &mut VariableDeclaration) {
+        declaration.location = Location::default();
+        self.walk_variable_declaration(declaration);
+    }
+
+    fn visit_assignment(&mut self, assignment: &mut Assignment) {
+        assignment.location = Location::default();
+        self.walk_assignment(assignment);
+    }
+
+    fn visit_if_conditional(&mut self, conditional: &mut IfConditional) {
+        conditional.location = Location::default();
+        self.walk_if_conditional(conditional);
+    }
+
+    fn visit_switch(&mut self, switch: &mut Switch) {
+        switch.location = Location::default();
+        self.walk_switch(switch);
+    }
+
+    fn visit_case(&mut self, case: &mut Case) {
+        case.location = Location::default();
+        self.walk_case(case);
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &mut ForLoop) {
+        for_loop.location = Location::default();
+        self.walk_for_loop(for_loop);
+    }
+
+    fn visit_function_call(&mut self, call: &mut FunctionCall) {
+        call.location = Location::default();
+        self.walk_function_call(call);
+    }
+
+    fn visit_identifier(&mut self, identifier: &mut Identifier) {
+        identifier.location = Location::default();
+    }
+
+    fn visit_literal(&mut self, literal: &mut Literal) {
+        literal.location = Location::default();
+    }
+}
@@ -0,0 +1,230 @@
+//!
+//! The Yul function name mangler.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor::Visitor;
+use crate::yul::parser::visitor_mut::VisitorMut;
+
+///
+/// The Yul function name mangler.
+///
+/// Yul allows functions with the same name in sibling blocks, whereas the LLVM IR functions of
+/// the whole object tree share a single namespace. The mangler gives each clashing function
+/// definition a unique name, and resolves the calls to the definitions visible in their scopes.
+///
+/// The first definition of each name keeps it, so the mangled names only appear where the
+/// clashes occur. The original names are kept in the syntax tree for the diagnostics and dumps.
+///
+/// The user-defined functions named like the `to_{type}` conversion builtins shadow the latter,
+/// so the calls to them are resolved to the user-defined functions as well.
+///
+#[derive(Debug, Default)]
+pub struct Mangler {
+    /// The names of all functions defined in the object tree, which the mangled names must avoid.
+    defined: HashSet<String>,
+    /// The names already given to the LLVM IR functions.
+    used: HashSet<String>,
+    /// The scope stack, mapping the visible function names to their mangled names, if any.
+    scopes: Vec<HashMap<String, Option<String>>>,
+}
+
+impl Mangler {
+    ///
+    /// Mangles the clashing function names in the object tree.
+    ///
+    pub fn mangle(object: &mut Object) {
+        let mut collector = Collector::default();
+        collector.visit_object(object);
+
+        let mut mangler = Self {
+            defined: collector.names,
+            ..Default::default()
+        };
+        mangler.visit_object(object);
+    }
+
+    ///
+    /// Gives the unique names to the functions defined in the `block`, and declares them in the
+    /// innermost scope, as they are visible in the whole block.
+    ///
+    fn hoist_functions(&mut self, block: &mut Block) {
+        for statement in block.statements.iter_mut() {
+            if let Statement::FunctionDefinition(definition) = statement {
+                definition.mangled_name = self.unique_name(definition.name.as_str());
+                self.scopes
+                    .last_mut()
+                    .expect("Always exists")
+                    .insert(definition.name.clone(), definition.mangled_name.clone());
+            }
+        }
+    }
+
+    ///
+    /// Returns a new name for the function `name`, if the latter is already used.
+    ///
+    fn unique_name(&mut self, name: &str) -> Option<String> {
+        if self.used.insert(name.to_owned()) {
+            return None;
+        }
+
+        let mut index = 1;
+        loop {
+            let candidate = format!("{}_{}", name, index);
+            if !self.defined.contains(candidate.as_str()) && self.used.insert(candidate.clone()) {
+                return Some(candidate);
+            }
+            index += 1;
+        }
+    }
+}
+
+impl VisitorMut for Mangler {
+    fn visit_block(&mut self, block: &mut Block) {
+        self.scopes.push(HashMap::new());
+        self.hoist_functions(block);
+        self.walk_block(block);
+        self.scopes.pop();
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &mut ForLoop) {
+        self.scopes.push(HashMap::new());
+        self.hoist_functions(&mut for_loop.initializer);
+        self.walk_block(&mut for_loop.initializer);
+        self.visit_expression(&mut for_loop.condition);
+        self.visit_block(&mut for_loop.finalizer);
+        self.visit_block(&mut for_loop.body);
+        self.scopes.pop();
+    }
+
+    fn visit_function_call(&mut self, call: &mut FunctionCall) {
+        if let Name::Conversion(_) = call.name {
+            let name = call.name.to_string();
+            if self
+                .scopes
+                .iter()
+                .any(|scope| scope.contains_key(name.as_str()))
+            {
+                call.name = Name::UserDefined(name);
+            }
+        }
+        if let Name::UserDefined(ref name) = call.name {
+            call.mangled_name = self
+                .scopes
+                .iter()
+                .rev()
+                .find_map(|scope| scope.get(name.as_str()))
+                .cloned()
+                .flatten();
+        }
+        self.walk_function_call(call);
+    }
+}
+
+///
+/// The collector of the function names defined in the object tree.
+///
+#[derive(Debug, Default)]
+struct Collector {
+    /// The collected function names.
+    names: HashSet<String>,
+}
+
+impl Visitor for Collector {
+    fn visit_function_definition(&mut self, definition: &FunctionDefinition) {
+        self.names.insert(definition.name.clone());
+        self.walk_function_definition(definition);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::Project;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::r#type::Type;
+    use crate::yul::parser::statement::expression::function_call::name::Name;
+    use crate::yul::parser::statement::expression::function_call::FunctionCall;
+    use crate::yul::parser::statement::object::Object;
+    use crate::yul::parser::visitor::Visitor;
+
+    use super::Mangler;
+
+    ///
+    /// The collector of the called function names.
+    ///
+    #[derive(Default)]
+    struct Calls {
+        /// The called function names, in the order of the calls.
+        names: Vec<Name>,
+    }
+
+    impl Visitor for Calls {
+        fn visit_function_call(&mut self, call: &FunctionCall) {
+            self.names.push(call.name.clone());
+            self.walk_function_call(call);
+        }
+    }
+
+    ///
+    /// Mangles the object with the deploy `code`, and returns the called function names.
+    ///
+    fn called_names(code: &str) -> Vec<Name> {
+        let mut lexer = Lexer::new(format!(r#"object "Test" {{ code {{ {} }} }}"#, code));
+        let mut object = Object::parse(&mut lexer, None).expect("Invalid test input");
+        Mangler::mangle(&mut object);
+
+        let mut calls = Calls::default();
+        calls.visit_object(&object);
+        calls.names
+    }
+
+    #[test]
+    fn conversion() {
+        assert_eq!(
+            called_names("pop(to_bool(1))"),
+            vec![Name::Pop, Name::Conversion(Type::Bool)],
+        );
+    }
+
+    #[test]
+    fn conversion_shadowed_by_user_function() {
+        let code = r#"
+            function to_bool(x) -> a, b {
+                a := x
+                b := to_uint8(x)
+            }
+            let a, b := to_bool(1)
+        "#;
+        assert_eq!(
+            called_names(code),
+            vec![
+                Name::Conversion(Type::UInt(8)),
+                Name::UserDefined("to_bool".to_owned()),
+            ],
+        );
+
+        let yul = format!(
+            r#"object "Test" {{ code {{ {} }} object "Test_deployed" {{ code {{ }} }} }}"#,
+            code
+        );
+        Project::try_from_test_yul(yul.as_str()).expect("Semantic error");
+    }
+
+    #[test]
+    fn conversion_to_unsupported_type() {
+        assert_eq!(
+            Name::from("to_uint7"),
+            Name::UserDefined("to_uint7".to_owned())
+        );
+        assert_eq!(Name::from("to_int"), Name::UserDefined("to_int".to_owned()));
+    }
+}
@@ -2,5 +2,14 @@
 //! The Yul IR compiling tools.
 //!
 
+pub mod analyzer;
+pub mod data_resolver;
+pub mod ext_code_checker;
 pub mod lexer;
+pub mod location_eraser;
+pub mod mangler;
+pub mod optimizer;
 pub mod parser;
+pub mod source_map;
+pub mod stub_checker;
+pub mod stub_trapper;
@@ -0,0 +1,153 @@
+//!
+//! The Yul constant folder.
+//!
+
+use num::One;
+use num::ToPrimitive;
+use num::Zero;
+
+use crate::yul::lexer::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::yul::lexer::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::parser::r#type::Type;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::visitor_mut::VisitorMut;
+
+///
+/// The Yul constant folder.
+///
+/// Replaces the calls to the unsigned arithmetic, comparison and bitwise builtins, whose
+/// arguments are all 256-bit integer literals, with the literals of their results. The nested
+/// calls are folded bottom-up, so whole constant expressions collapse into single literals.
+///
+#[derive(Debug, Default)]
+pub struct ConstantFolder {}
+
+impl ConstantFolder {
+    ///
+    /// Folds the constant expressions in the object tree.
+    ///
+    pub fn fold(object: &mut Object) {
+        Self::default().visit_object(object);
+    }
+
+    ///
+    /// Evaluates the builtin function `name` on the `arguments`.
+    ///
+    /// Returns `None` if the function cannot be evaluated at compile time.
+    ///
+    fn evaluate(name: &Name, arguments: &[num::BigUint]) -> Option<num::BigUint> {
+        let modulus = num::BigUint::one() << compiler_common::BITLENGTH_FIELD;
+        let mask = &modulus - num::BigUint::one();
+        let boolean = |value: bool| {
+            if value {
+                num::BigUint::one()
+            } else {
+                num::BigUint::zero()
+            }
+        };
+
+        Some(match (name, arguments) {
+            (Name::Add, [x, y]) => (x + y) & &mask,
+            (Name::Sub, [x, y]) => (x + &modulus - y) & &mask,
+            (Name::Mul, [x, y]) => (x * y) & &mask,
+            (Name::Div, [_, y]) if y.is_zero() => num::BigUint::zero(),
+            (Name::Div, [x, y]) => x / y,
+            (Name::Mod, [_, y]) if y.is_zero() => num::BigUint::zero(),
+            (Name::Mod, [x, y]) => x % y,
+            (Name::Exp, [x, y]) => x.modpow(y, &modulus),
+            (Name::AddMod, [_, _, m]) if m.is_zero() => num::BigUint::zero(),
+            (Name::AddMod, [x, y, m]) => (x + y) % m,
+            (Name::MulMod, [_, _, m]) if m.is_zero() => num::BigUint::zero(),
+            (Name::MulMod, [x, y, m]) => (x * y) % m,
+
+            (Name::Lt, [x, y]) => boolean(x < y),
+            (Name::Gt, [x, y]) => boolean(x > y),
+            (Name::Eq, [x, y]) => boolean(x == y),
+            (Name::IsZero, [x]) => boolean(x.is_zero()),
+
+            (Name::And, [x, y]) => x & y,
+            (Name::Or, [x, y]) => x | y,
+            (Name::Xor, [x, y]) => x ^ y,
+            (Name::Not, [x]) => x ^ &mask,
+            (Name::Shl, [shift, x]) => match shift.to_usize() {
+                Some(shift) if shift < compiler_common::BITLENGTH_FIELD => (x << shift) & &mask,
+                _ => num::BigUint::zero(),
+            },
+            (Name::Shr, [shift, x]) => match shift.to_usize() {
+                Some(shift) if shift < compiler_common::BITLENGTH_FIELD => x >> shift,
+                _ => num::BigUint::zero(),
+            },
+            (Name::Byte, [index, x]) => match index.to_usize() {
+                Some(index) if index < compiler_common::SIZE_FIELD => {
+                    let shift =
+                        (compiler_common::SIZE_FIELD - 1 - index) * compiler_common::BITLENGTH_BYTE;
+                    (x >> shift) & num::BigUint::from(u8::MAX)
+                }
+                _ => num::BigUint::zero(),
+            },
+
+            _ => return None,
+        })
+    }
+
+    ///
+    /// Returns the value of the untyped or 256-bit unsigned integer literal `expression`.
+    ///
+    fn integer_value(expression: &Expression) -> Option<num::BigUint> {
+        match expression {
+            Expression::Literal(Literal {
+                inner: LexicalLiteral::Integer(inner),
+                yul_type,
+                ..
+            }) if yul_type.to_owned().unwrap_or_default() == Type::default() => {
+                Some(inner.to_biguint())
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Creates the integer literal of the `value`.
+    ///
+    /// The small values are written in decimal, and the large ones, which are usually masks or
+    /// selectors, in hexadecimal.
+    ///
+    fn integer_literal(value: num::BigUint) -> LexicalLiteral {
+        let literal = if value.bits() > u64::BITS as u64 {
+            IntegerLiteral::new_hexadecimal(format!("0x{}", value.to_str_radix(16)))
+        } else {
+            IntegerLiteral::new_decimal(value.to_string())
+        };
+        LexicalLiteral::Integer(literal)
+    }
+}
+
+impl VisitorMut for ConstantFolder {
+    fn visit_expression(&mut self, expression: &mut Expression) {
+        self.walk_expression(expression);
+
+        let call = match expression {
+            Expression::FunctionCall(call) if call.name.is_pure() => call,
+            _ => return,
+        };
+        let arguments = match call
+            .arguments
+            .iter()
+            .map(Self::integer_value)
+            .collect::<Option<Vec<num::BigUint>>>()
+        {
+            Some(arguments) => arguments,
+            None => return,
+        };
+        if let Some(value) = Self::evaluate(&call.name, arguments.as_slice()) {
+            *expression = Expression::Literal(Literal {
+                location: call.location.clone(),
+                inner: Self::integer_literal(value),
+                yul_type: None,
+            });
+        }
+    }
+}
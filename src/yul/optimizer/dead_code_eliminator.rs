@@ -0,0 +1,110 @@
+//!
+//! The Yul dead code eliminator.
+//!
+
+use num::Zero;
+
+use crate::yul::lexer::lexeme::literal::boolean::Boolean as BooleanLiteral;
+use crate::yul::lexer::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor_mut::VisitorMut;
+
+///
+/// The Yul dead code eliminator.
+///
+/// Removes the statements following a `leave`, `break`, `continue`, or a call to a terminating
+/// builtin like `revert` or `return` in the same block. The function definitions are kept, as
+/// they are visible in the whole block.
+///
+/// Also resolves the constant conditions, which usually appear after the constant folding:
+/// `if` with a zero condition is removed, and with a non-zero one is replaced with its block.
+/// `for` with a zero condition is replaced with its initializer.
+///
+#[derive(Debug, Default)]
+pub struct DeadCodeEliminator {}
+
+impl DeadCodeEliminator {
+    ///
+    /// Eliminates the dead code in the object tree.
+    ///
+    pub fn eliminate(object: &mut Object) {
+        Self::default().visit_object(object);
+    }
+
+    ///
+    /// Returns the value of the `expression` as a condition, if it is a constant.
+    ///
+    fn constant_condition(expression: &Expression) -> Option<bool> {
+        match expression {
+            Expression::Literal(Literal {
+                inner: LexicalLiteral::Integer(inner),
+                ..
+            }) => Some(!inner.to_biguint().is_zero()),
+            Expression::Literal(Literal {
+                inner: LexicalLiteral::Boolean(inner),
+                ..
+            }) => Some(*inner == BooleanLiteral::True),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Checks whether the control flow never reaches the statement after the `statement`.
+    ///
+    fn is_terminating(statement: &Statement) -> bool {
+        match statement {
+            Statement::Leave(_) | Statement::Break(_) | Statement::Continue(_) => true,
+            Statement::Expression(Expression::FunctionCall(FunctionCall { name, .. })) => {
+                name.is_terminating()
+            }
+            Statement::Block(Block { statements, .. }) => statements
+                .iter()
+                .rev()
+                .find(|statement| !matches!(statement, Statement::FunctionDefinition(_)))
+                .map(Self::is_terminating)
+                .unwrap_or_default(),
+            _ => false,
+        }
+    }
+}
+
+impl VisitorMut for DeadCodeEliminator {
+    fn visit_block(&mut self, block: &mut Block) {
+        self.walk_block(block);
+
+        let mut statements = Vec::with_capacity(block.statements.len());
+        let mut is_terminated = false;
+        for statement in block.statements.drain(..) {
+            let statement = match statement {
+                statement @ Statement::FunctionDefinition(_) => {
+                    statements.push(statement);
+                    continue;
+                }
+                _ if is_terminated => continue,
+                Statement::IfConditional(conditional) => {
+                    match Self::constant_condition(&conditional.condition) {
+                        Some(true) => Statement::Block(conditional.block),
+                        Some(false) => continue,
+                        None => Statement::IfConditional(conditional),
+                    }
+                }
+                Statement::ForLoop(for_loop) => {
+                    match Self::constant_condition(&for_loop.condition) {
+                        Some(false) => Statement::Block(for_loop.initializer),
+                        _ => Statement::ForLoop(for_loop),
+                    }
+                }
+                statement => statement,
+            };
+
+            is_terminated = Self::is_terminating(&statement);
+            statements.push(statement);
+        }
+        block.statements = statements;
+    }
+}
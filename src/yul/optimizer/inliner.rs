@@ -0,0 +1,216 @@
+//!
+//! The Yul function inliner.
+//!
+
+use std::collections::HashMap;
+
+use crate::yul::parser::r#type::Type;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor::Visitor;
+use crate::yul::parser::visitor_mut::VisitorMut;
+
+///
+/// The Yul function inliner.
+///
+/// Replaces the calls to the small expression functions, whose body is a single assignment of
+/// the only return variable, like `function f(x) -> y { y := and(x, 0xff) }`, with the body
+/// expression, where the parameters are substituted by the arguments.
+///
+/// To keep the evaluation order and count of the arguments, only the calls whose arguments are
+/// all identifiers or literals are inlined. Both the function and the arguments must be untyped,
+/// so no implicit conversions are lost at the call boundary.
+///
+/// The body expression must only call the builtins, as the user-defined functions visible at the
+/// call site may differ from those visible at the definition, and the function name must be
+/// defined once in the object tree for the same reason. The functions left uncalled are removed
+/// by the unused removal pass.
+///
+#[derive(Debug, Default)]
+pub struct Inliner {
+    /// The inlinable functions, by their names.
+    functions: HashMap<String, Inlinable>,
+}
+
+///
+/// The inlinable function.
+///
+#[derive(Debug, Clone)]
+struct Inlinable {
+    /// The parameter names.
+    parameters: Vec<String>,
+    /// The body expression.
+    expression: Expression,
+}
+
+impl Inliner {
+    /// The maximal number of the body expression nodes.
+    pub const SIZE_THRESHOLD: usize = 8;
+
+    ///
+    /// Inlines the small functions in the object tree.
+    ///
+    pub fn inline(object: &mut Object) {
+        let mut collector = Collector::default();
+        collector.visit_object(object);
+
+        let functions = collector
+            .functions
+            .into_iter()
+            .filter_map(|(name, definitions)| match definitions.as_slice() {
+                [Some(function)] => Some((name, function.to_owned())),
+                _ => None,
+            })
+            .collect();
+
+        Self { functions }.visit_object(object);
+    }
+
+    ///
+    /// Returns the inlinable form of the function `definition`, if it qualifies.
+    ///
+    fn inlinable(definition: &FunctionDefinition) -> Option<Inlinable> {
+        let is_untyped = definition
+            .arguments
+            .iter()
+            .chain(definition.result.iter())
+            .all(|identifier| {
+                identifier.yul_type.to_owned().unwrap_or_default() == Type::default()
+            });
+        if !is_untyped {
+            return None;
+        }
+
+        let result = match definition.result.as_slice() {
+            [result] => result,
+            _ => return None,
+        };
+        let expression = match definition.body.statements.as_slice() {
+            [Statement::Assignment(Assignment {
+                bindings,
+                initializer,
+                ..
+            })] if bindings.len() == 1 && bindings[0].name == result.name => initializer,
+            _ => return None,
+        };
+
+        let parameters: Vec<String> = definition
+            .arguments
+            .iter()
+            .map(|argument| argument.name.clone())
+            .collect();
+        if Self::size(expression, parameters.as_slice())? > Self::SIZE_THRESHOLD {
+            return None;
+        }
+
+        Some(Inlinable {
+            parameters,
+            expression: expression.to_owned(),
+        })
+    }
+
+    ///
+    /// Returns the number of the `expression` nodes, or `None` if it calls a user-defined
+    /// function or uses an identifier other than the `parameters`.
+    ///
+    fn size(expression: &Expression, parameters: &[String]) -> Option<usize> {
+        match expression {
+            Expression::Literal(_) => Some(1),
+            Expression::Identifier(identifier) => {
+                if parameters.contains(&identifier.name) {
+                    Some(1)
+                } else {
+                    None
+                }
+            }
+            Expression::FunctionCall(call) => {
+                if let Name::UserDefined(_) = call.name {
+                    return None;
+                }
+                call.arguments
+                    .iter()
+                    .map(|argument| Self::size(argument, parameters))
+                    .sum::<Option<usize>>()
+                    .map(|size| size + 1)
+            }
+        }
+    }
+
+    ///
+    /// Substitutes the parameters in the `expression` with the `arguments`.
+    ///
+    fn substitute(expression: &mut Expression, arguments: &HashMap<&str, &Expression>) {
+        match expression {
+            Expression::Identifier(identifier) => {
+                if let Some(argument) = arguments.get(identifier.name.as_str()) {
+                    *expression = (*argument).to_owned();
+                }
+            }
+            Expression::FunctionCall(call) => {
+                for argument in call.arguments.iter_mut() {
+                    Self::substitute(argument, arguments);
+                }
+            }
+            Expression::Literal(_) => {}
+        }
+    }
+}
+
+impl VisitorMut for Inliner {
+    fn visit_expression(&mut self, expression: &mut Expression) {
+        self.walk_expression(expression);
+
+        let call = match expression {
+            Expression::FunctionCall(call) => call,
+            _ => return,
+        };
+        let function = match call.name {
+            Name::UserDefined(ref name) => match self.functions.get(name.as_str()) {
+                Some(function) => function,
+                None => return,
+            },
+            _ => return,
+        };
+        if call.arguments.len() != function.parameters.len()
+            || !call.arguments.iter().all(|argument| {
+                matches!(argument, Expression::Identifier(_) | Expression::Literal(_))
+                    && argument.yul_type() == Type::default()
+            })
+        {
+            return;
+        }
+
+        let arguments = function
+            .parameters
+            .iter()
+            .map(String::as_str)
+            .zip(call.arguments.iter())
+            .collect();
+        let mut inlined = function.expression.to_owned();
+        Self::substitute(&mut inlined, &arguments);
+        *expression = inlined;
+    }
+}
+
+///
+/// The function definitions collector.
+///
+#[derive(Debug, Default)]
+struct Collector {
+    /// The definitions of each function name, which are `None` if not inlinable.
+    functions: HashMap<String, Vec<Option<Inlinable>>>,
+}
+
+impl Visitor for Collector {
+    fn visit_function_definition(&mut self, definition: &FunctionDefinition) {
+        self.functions
+            .entry(definition.name.clone())
+            .or_default()
+            .push(Inliner::inlinable(definition));
+        self.walk_function_definition(definition);
+    }
+}
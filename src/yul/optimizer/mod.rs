@@ -0,0 +1,54 @@
+//!
+//! The Yul optimizer.
+//!
+
+pub mod constant_folder;
+pub mod dead_code_eliminator;
+pub mod inliner;
+pub mod pass;
+pub mod unused_remover;
+
+use crate::yul::parser::statement::object::Object;
+
+use self::pass::Pass;
+
+///
+/// The Yul optimizer.
+///
+/// Runs the selected passes over the syntax tree between the semantic analysis and the LLVM IR
+/// generation. The passes rely on the types annotated by the analyzer, and keep the tree valid
+/// for the lowering, so they can be switched on and off independently and in any order.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Optimizer {
+    /// The passes to run, in order.
+    passes: Vec<Pass>,
+}
+
+impl Optimizer {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(passes: Vec<Pass>) -> Self {
+        Self { passes }
+    }
+
+    ///
+    /// Runs the passes over the object tree.
+    ///
+    /// If `dump` is set, the Yul code is printed after each pass.
+    ///
+    pub fn optimize(&self, object: &mut Object, contract_path: &str, dump: bool) {
+        for pass in self.passes.iter() {
+            pass.run(object);
+
+            if dump {
+                eprintln!(
+                    "Contract `{}` Yul after the `{}` pass:\n",
+                    contract_path, pass
+                );
+                println!("{}", object);
+            }
+        }
+    }
+}
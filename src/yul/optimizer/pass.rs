@@ -0,0 +1,103 @@
+//!
+//! The Yul optimizer pass.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::yul::optimizer::constant_folder::ConstantFolder;
+use crate::yul::optimizer::dead_code_eliminator::DeadCodeEliminator;
+use crate::yul::optimizer::inliner::Inliner;
+use crate::yul::optimizer::unused_remover::UnusedRemover;
+use crate::yul::parser::statement::object::Object;
+
+///
+/// The Yul optimizer pass.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pass {
+    /// Replaces the calls to small expression functions with their bodies.
+    Inlining,
+    /// Evaluates the builtin calls on literals.
+    ConstantFolding,
+    /// Removes the unreachable code and the constant conditions.
+    DeadCodeElimination,
+    /// Removes the unused functions and variables.
+    UnusedRemoval,
+}
+
+impl Pass {
+    /// The keyword selecting the default pipeline.
+    pub const ALL: &'static str = "all";
+
+    ///
+    /// Returns the default pipeline, where each pass makes the most of the previous ones.
+    ///
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Inlining,
+            Self::ConstantFolding,
+            Self::DeadCodeElimination,
+            Self::UnusedRemoval,
+        ]
+    }
+
+    ///
+    /// Parses the comma-separated list of the pass names, or the `all` keyword.
+    ///
+    pub fn parse_list(input: &str) -> anyhow::Result<Vec<Self>> {
+        if input.trim() == Self::ALL {
+            return Ok(Self::all());
+        }
+
+        input
+            .split(',')
+            .map(|name| Self::from_str(name.trim()))
+            .collect()
+    }
+
+    ///
+    /// Runs the pass over the object tree.
+    ///
+    pub fn run(&self, object: &mut Object) {
+        match self {
+            Self::Inlining => Inliner::inline(object),
+            Self::ConstantFolding => ConstantFolder::fold(object),
+            Self::DeadCodeElimination => DeadCodeEliminator::eliminate(object),
+            Self::UnusedRemoval => UnusedRemover::remove(object),
+        }
+    }
+}
+
+impl FromStr for Pass {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "inlining" => Ok(Self::Inlining),
+            "constant-folding" => Ok(Self::ConstantFolding),
+            "dead-code-elimination" => Ok(Self::DeadCodeElimination),
+            "unused-removal" => Ok(Self::UnusedRemoval),
+            _ => anyhow::bail!(
+                "Unknown Yul optimizer pass `{}`. Available passes: {}",
+                input,
+                Self::all()
+                    .into_iter()
+                    .map(|pass| pass.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Pass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Inlining => write!(f, "inlining"),
+            Self::ConstantFolding => write!(f, "constant-folding"),
+            Self::DeadCodeElimination => write!(f, "dead-code-elimination"),
+            Self::UnusedRemoval => write!(f, "unused-removal"),
+        }
+    }
+}
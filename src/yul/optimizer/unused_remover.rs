@@ -0,0 +1,161 @@
+//!
+//! The Yul unused function and variable remover.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor::Visitor;
+use crate::yul::parser::visitor_mut::VisitorMut;
+
+///
+/// The Yul unused function and variable remover.
+///
+/// Removes the function definitions which are never called, except by themselves, and the
+/// variable declarations whose variables are never used, if their initializers have no side
+/// effects. Removing a definition may leave others unused, so the removal is repeated until
+/// nothing changes.
+///
+/// The usages are collected by name over the whole object tree, so a name used anywhere keeps
+/// all definitions of that name. This is conservative, but does not require resolving scopes.
+///
+#[derive(Debug, Default)]
+pub struct UnusedRemover {
+    /// The usages collected before the removal.
+    usages: Usages,
+    /// Whether anything has been removed.
+    is_changed: bool,
+}
+
+impl UnusedRemover {
+    ///
+    /// Removes the unused functions and variables in the object tree.
+    ///
+    pub fn remove(object: &mut Object) {
+        loop {
+            let mut usages = Usages::default();
+            usages.visit_object(object);
+
+            let mut remover = Self {
+                usages,
+                is_changed: false,
+            };
+            remover.visit_object(object);
+            if !remover.is_changed {
+                break;
+            }
+        }
+    }
+
+    ///
+    /// Checks whether the `statement` can be removed.
+    ///
+    fn is_unused(&self, statement: &Statement) -> bool {
+        match statement {
+            Statement::FunctionDefinition(definition) => {
+                !self.usages.calls.contains(definition.name.as_str())
+            }
+            Statement::VariableDeclaration(VariableDeclaration {
+                bindings,
+                expression,
+                ..
+            }) => {
+                bindings
+                    .iter()
+                    .all(|binding| !self.usages.variables.contains(binding.name.as_str()))
+                    && expression.as_ref().map(Self::is_removable).unwrap_or(true)
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks whether the `expression` has no side effects, so it can be removed if its value is
+    /// unused.
+    ///
+    fn is_removable(expression: &Expression) -> bool {
+        match expression {
+            Expression::Literal(_) | Expression::Identifier(_) => true,
+            Expression::FunctionCall(call) => {
+                call.name.is_pure() && call.arguments.iter().all(Self::is_removable)
+            }
+        }
+    }
+}
+
+impl VisitorMut for UnusedRemover {
+    fn visit_block(&mut self, block: &mut Block) {
+        self.walk_block(block);
+
+        let length = block.statements.len();
+        let statements = std::mem::take(&mut block.statements);
+        block.statements = statements
+            .into_iter()
+            .filter(|statement| !self.is_unused(statement))
+            .collect();
+        self.is_changed |= block.statements.len() != length;
+    }
+}
+
+///
+/// The function and variable usages collector.
+///
+#[derive(Debug, Default)]
+struct Usages {
+    /// The names of the functions called outside their own bodies.
+    calls: HashSet<String>,
+    /// The names of the variables which are read or assigned.
+    variables: HashSet<String>,
+    /// The names of the functions being visited, with their nesting depth.
+    functions: HashMap<String, usize>,
+}
+
+impl Visitor for Usages {
+    fn visit_function_definition(&mut self, definition: &FunctionDefinition) {
+        *self.functions.entry(definition.name.clone()).or_default() += 1;
+        self.walk_function_definition(definition);
+        if let Some(depth) = self.functions.get_mut(definition.name.as_str()) {
+            *depth -= 1;
+        }
+    }
+
+    fn visit_assignment(&mut self, assignment: &Assignment) {
+        for binding in assignment.bindings.iter() {
+            self.variables.insert(binding.name.clone());
+        }
+        self.visit_expression(&assignment.initializer);
+    }
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Identifier(identifier) => {
+                self.variables.insert(identifier.name.clone());
+            }
+            expression => self.walk_expression(expression),
+        }
+    }
+
+    fn visit_function_call(&mut self, call: &FunctionCall) {
+        if let Name::UserDefined(ref name) = call.name {
+            if self
+                .functions
+                .get(name.as_str())
+                .copied()
+                .unwrap_or_default()
+                == 0
+            {
+                self.calls.insert(name.clone());
+            }
+        }
+        self.walk_function_call(call);
+    }
+}
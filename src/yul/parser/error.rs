@@ -0,0 +1,44 @@
+//!
+//! The Yul parser error.
+//!
+
+use std::fmt;
+
+use crate::yul::lexer::token::Token;
+
+///
+/// The Yul parser error.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// An unexpected token has been received from the lexer.
+    InvalidToken {
+        /// The invalid token.
+        token: Token,
+        /// The list of the expected lexemes.
+        expected: Vec<&'static str>,
+    },
+}
+
+impl Error {
+    ///
+    /// A shortcut constructor of the invalid token error.
+    ///
+    pub fn invalid_token(token: Token, expected: Vec<&'static str>) -> Self {
+        Self::InvalidToken { token, expected }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidToken { token, expected } => write!(
+                f,
+                "{}: Expected one of {:?}, found `{}`",
+                token.location, expected, token.lexeme
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -2,19 +2,90 @@
 //! The YUL code block.
 //!
 
+pub mod error;
 pub mod identifier;
 pub mod statement;
 pub mod r#type;
+pub mod visitor;
+pub mod visitor_mut;
 
+use std::fmt;
+
+use crate::yul::lexer::lexeme::keyword::Keyword;
+use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 
 ///
 /// Returns the `token` value if it is `Some(_)`, otherwise takes the next token from the `stream`.
 ///
-pub fn take_or_next(mut lexeme: Option<Lexeme>, lexer: &mut Lexer) -> anyhow::Result<Lexeme> {
-    match lexeme.take() {
-        Some(lexeme) => Ok(lexeme),
+pub fn take_or_next(mut token: Option<Token>, lexer: &mut Lexer) -> anyhow::Result<Token> {
+    match token.take() {
+        Some(token) => Ok(token),
         None => Ok(lexer.next()?),
     }
 }
+
+///
+/// Records the `error` and skips the tokens until the next statement boundary in the error
+/// recovery mode.
+///
+/// Returns either a keyword starting a statement, or the `}` closing the current block, or the
+/// end of file. Nested blocks are skipped entirely. The skipping starts from the invalid token
+/// of the `error`, if any, since it may be a bracket that must be taken into account.
+///
+pub fn synchronize(lexer: &mut Lexer, error: anyhow::Error) -> anyhow::Result<Token> {
+    let mut initial = match error.downcast_ref::<ParserError>() {
+        Some(ParserError::InvalidToken { token, .. }) => Some(token.to_owned()),
+        _ => None,
+    };
+    lexer.recover(error)?;
+
+    let mut depth = 0;
+    loop {
+        let token = match take_or_next(initial.take(), lexer) {
+            Ok(token) => token,
+            Err(error) => {
+                lexer.recover(error)?;
+                continue;
+            }
+        };
+
+        match token.lexeme {
+            Lexeme::EndOfFile => return Ok(token),
+            Lexeme::Symbol(Symbol::BracketCurlyLeft) => depth += 1,
+            Lexeme::Symbol(Symbol::BracketCurlyRight) if depth == 0 => return Ok(token),
+            Lexeme::Symbol(Symbol::BracketCurlyRight) => depth -= 1,
+            Lexeme::Keyword(
+                Keyword::Function
+                | Keyword::Let
+                | Keyword::If
+                | Keyword::Switch
+                | Keyword::For
+                | Keyword::Continue
+                | Keyword::Break
+                | Keyword::Leave,
+            ) if depth == 0 => return Ok(token),
+            _ => {}
+        }
+    }
+}
+
+///
+/// Writes the `item` to the formatter, indenting each of its lines, and terminates it with a
+/// new line.
+///
+/// Used by the pretty-printer, which relies on the YUL string literals never containing
+/// line breaks.
+///
+pub fn write_indented<T>(f: &mut fmt::Formatter<'_>, item: &T) -> fmt::Result
+where
+    T: fmt::Display,
+{
+    for line in item.to_string().lines() {
+        writeln!(f, "    {}", line)?;
+    }
+    Ok(())
+}
@@ -2,10 +2,16 @@
 //! The assignment expression statement.
 //!
 
+use std::fmt;
+
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::r#type::Type;
 use crate::yul::parser::statement::expression::Expression;
 
 ///
@@ -13,8 +19,10 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Assignment {
+    /// The location.
+    pub location: Location,
     /// The variable bindings.
-    pub bindings: Vec<String>,
+    pub bindings: Vec<Identifier>,
     /// The initializing expression.
     pub initializer: Expression,
 }
@@ -23,43 +31,33 @@ impl Assignment {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
-
-        let identifier = match lexeme {
-            Lexeme::Identifier(identifier) => identifier,
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{identifier}"], lexeme);
-            }
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                ref location,
+                lexeme: Lexeme::Identifier(_),
+                ..
+            } => location.clone(),
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["{identifier}"])),
         };
 
-        match lexer.peek()? {
-            Lexeme::Symbol(Symbol::Assignment) => {
-                lexer.next()?;
-
-                Ok(Self {
-                    bindings: vec![identifier],
-                    initializer: Expression::parse(lexer, None)?,
-                })
-            }
-            Lexeme::Symbol(Symbol::Comma) => {
-                let (identifiers, next) =
-                    Identifier::parse_list(lexer, Some(Lexeme::Identifier(identifier)))?;
-
-                match crate::yul::parser::take_or_next(next, lexer)? {
-                    Lexeme::Symbol(Symbol::Assignment) => {}
-                    lexeme => {
-                        anyhow::bail!("Expected one of {:?}, found `{}`", [":="], lexeme);
-                    }
-                }
-
-                Ok(Self {
-                    bindings: identifiers,
-                    initializer: Expression::parse(lexer, None)?,
-                })
-            }
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", [":=", ","], lexeme),
+        let (bindings, next) = Identifier::parse_list(lexer, Some(token))?;
+
+        match crate::yul::parser::take_or_next(next, lexer)? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Assignment),
+                ..
+            } => {}
+            token => anyhow::bail!(ParserError::invalid_token(token, vec![":=", ","])),
         }
+
+        Ok(Self {
+            location,
+            bindings,
+            initializer: Expression::parse(lexer, None)?,
+        })
     }
 }
 
@@ -68,14 +66,21 @@ where
     D: compiler_llvm_context::Dependency,
 {
     fn into_llvm(mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        let initializer_type = self.initializer.yul_type();
         let value = match self.initializer.into_llvm(context)? {
             Some(value) => value,
             None => return Ok(()),
         };
 
         if self.bindings.len() == 1 {
-            let name = self.bindings.remove(0);
-            context.build_store(context.function().stack[name.as_str()], value.to_llvm());
+            let identifier = self.bindings.remove(0);
+            let pointer = context.function().stack[identifier.name.as_str()];
+            let value = initializer_type.convert_llvm(
+                context,
+                value.to_llvm().into_int_value(),
+                pointer.get_type().get_element_type().into_int_type(),
+            );
+            context.build_store(pointer, value);
             return Ok(());
         }
 
@@ -97,14 +102,33 @@ where
                 )
             };
 
-            let value = context.build_load(
-                pointer,
-                format!("assignment_binding_{}_value", index).as_str(),
-            );
+            let value = context
+                .build_load(
+                    pointer,
+                    format!("assignment_binding_{}_value", index).as_str(),
+                )
+                .into_int_value();
 
-            context.build_store(context.function().stack[binding.as_str()], value);
+            let pointer = context.function().stack[binding.name.as_str()];
+            let value = Type::default().convert_llvm(
+                context,
+                value,
+                pointer.get_type().get_element_type().into_int_type(),
+            );
+            context.build_store(pointer, value);
         }
 
         Ok(())
     }
 }
+
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} := {}",
+            Identifier::join(self.bindings.as_slice()),
+            self.initializer
+        )
+    }
+}
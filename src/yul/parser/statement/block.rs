@@ -2,18 +2,26 @@
 //! The source code block.
 //!
 
+use std::fmt;
+
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::assignment::Assignment;
 use crate::yul::parser::statement::expression::Expression;
 use crate::yul::parser::statement::Statement;
+use crate::yul::source_map::error::Error as SourceMapError;
 
 ///
 /// The source code block.
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Block {
+    /// The location.
+    pub location: Location,
     /// The block statements.
     pub statements: Vec<Statement>,
 }
@@ -22,61 +30,110 @@ impl Block {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
         let mut statements = Vec::new();
 
-        match lexeme {
-            Lexeme::Symbol(Symbol::BracketCurlyLeft) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["{"], lexeme),
-        }
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["{"])),
+        };
 
         let mut remaining = None;
 
         loop {
-            match crate::yul::parser::take_or_next(remaining.take(), lexer)? {
-                lexeme @ Lexeme::Keyword(_) => {
-                    let (statement, next) = Statement::parse(lexer, Some(lexeme))?;
+            let token = match crate::yul::parser::take_or_next(remaining.take(), lexer) {
+                Ok(Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    ..
+                }) => break,
+                Ok(token) => token,
+                Err(error) => {
+                    lexer.recover(error)?;
+                    continue;
+                }
+            };
+
+            match Self::parse_statement(lexer, token) {
+                Ok((statement, next)) => {
                     remaining = next;
                     statements.push(statement);
                 }
-                lexeme @ Lexeme::Literal(_) => {
-                    statements
-                        .push(Expression::parse(lexer, Some(lexeme)).map(Statement::Expression)?);
-                }
-                lexeme @ Lexeme::Identifier(_) => match lexer.peek()? {
-                    Lexeme::Symbol(Symbol::Assignment) => {
-                        statements.push(
-                            Assignment::parse(lexer, Some(lexeme)).map(Statement::Assignment)?,
-                        );
-                    }
-                    Lexeme::Symbol(Symbol::Comma) => {
-                        statements.push(
-                            Assignment::parse(lexer, Some(lexeme)).map(Statement::Assignment)?,
-                        );
-                    }
-                    _ => {
-                        statements.push(
-                            Expression::parse(lexer, Some(lexeme)).map(Statement::Expression)?,
-                        );
-                    }
+                Err(error) => match crate::yul::parser::synchronize(lexer, error)? {
+                    Token {
+                        lexeme: Lexeme::EndOfFile,
+                        ..
+                    } => break,
+                    token => remaining = Some(token),
                 },
-                lexeme @ Lexeme::Symbol(Symbol::BracketCurlyLeft) => {
-                    statements.push(Block::parse(lexer, Some(lexeme)).map(Statement::Block)?)
-                }
-                Lexeme::Symbol(Symbol::BracketCurlyRight) => break,
-                lexeme => {
-                    anyhow::bail!(
-                        "Expected one of {:?}, found `{}`",
-                        ["{keyword}", "{expression}", "{identifier}", "{", "}"],
-                        lexeme
-                    );
-                }
             }
         }
 
-        Ok(Self { statements })
+        Ok(Self {
+            location,
+            statements,
+        })
+    }
+
+    ///
+    /// Parses a single statement of the block, starting with the `token`.
+    ///
+    fn parse_statement(
+        lexer: &mut Lexer,
+        token: Token,
+    ) -> anyhow::Result<(Statement, Option<Token>)> {
+        match token {
+            token @ Token {
+                lexeme: Lexeme::Keyword(_),
+                ..
+            } => Statement::parse(lexer, Some(token)),
+            token @ Token {
+                lexeme: Lexeme::Literal(_),
+                ..
+            } => Ok((
+                Expression::parse(lexer, Some(token)).map(Statement::Expression)?,
+                None,
+            )),
+            token @ Token {
+                lexeme: Lexeme::Identifier(_),
+                ..
+            } => match lexer.peek()? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Assignment),
+                    ..
+                } => Ok((
+                    Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
+                    None,
+                )),
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => Ok((
+                    Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
+                    None,
+                )),
+                _ => Ok((
+                    Expression::parse(lexer, Some(token)).map(Statement::Expression)?,
+                    None,
+                )),
+            },
+            token @ Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                ..
+            } => Ok((
+                Block::parse(lexer, Some(token)).map(Statement::Block)?,
+                None,
+            )),
+            token => anyhow::bail!(ParserError::invalid_token(
+                token,
+                vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+            )),
+        }
     }
 }
 
@@ -102,40 +159,55 @@ where
         }
 
         for function in functions.into_iter() {
-            function.into_llvm(context)?;
+            let location = function.location.clone();
+            function
+                .into_llvm(context)
+                .map_err(|error| SourceMapError::wrap(location, error))?;
         }
 
         context.set_function(current_function.clone());
         context.set_basic_block(current_block);
         for statement in local_statements.into_iter() {
+            let location = statement.location();
             match statement {
-                Statement::Block(block) => {
-                    block.into_llvm(context)?;
-                }
-                Statement::Expression(expression) => {
-                    expression.into_llvm(context)?;
-                }
-                Statement::VariableDeclaration(statement) => statement.into_llvm(context)?,
-                Statement::Assignment(statement) => statement.into_llvm(context)?,
-                Statement::IfConditional(statement) => statement.into_llvm(context)?,
-                Statement::Switch(statement) => statement.into_llvm(context)?,
-                Statement::ForLoop(statement) => statement.into_llvm(context)?,
-                Statement::Continue => {
+                Statement::Block(block) => block.into_llvm(context),
+                Statement::Expression(expression) => expression.into_llvm(context).map(|_| ()),
+                Statement::VariableDeclaration(statement) => statement.into_llvm(context),
+                Statement::Assignment(statement) => statement.into_llvm(context),
+                Statement::IfConditional(statement) => statement.into_llvm(context),
+                Statement::Switch(statement) => statement.into_llvm(context),
+                Statement::ForLoop(statement) => statement.into_llvm(context),
+                Statement::Continue(_) => {
                     context.build_unconditional_branch(context.r#loop().continue_block);
                     break;
                 }
-                Statement::Break => {
+                Statement::Break(_) => {
                     context.build_unconditional_branch(context.r#loop().join_block);
                     break;
                 }
-                Statement::Leave => {
+                Statement::Leave(_) => {
                     context.build_unconditional_branch(context.function().return_block);
                     break;
                 }
                 statement => anyhow::bail!("Unexpected local statement: {:?}", statement),
             }
+            .map_err(|error| SourceMapError::wrap(location, error))?;
         }
 
         Ok(())
     }
 }
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.statements.is_empty() {
+            return write!(f, "{{ }}");
+        }
+
+        writeln!(f, "{{")?;
+        for statement in self.statements.iter() {
+            crate::yul::parser::write_indented(f, statement)?;
+        }
+        write!(f, "}}")
+    }
+}
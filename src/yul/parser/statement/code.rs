@@ -2,9 +2,14 @@
 //! The YUL code.
 //!
 
+use std::fmt;
+
 use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::block::Block;
 
 ///
@@ -12,6 +17,8 @@ use crate::yul::parser::statement::block::Block;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Code {
+    /// The location.
+    pub location: Location,
     /// The main block.
     pub block: Block,
 }
@@ -20,17 +27,21 @@ impl Code {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
-
-        match lexeme {
-            Lexeme::Keyword(Keyword::Code) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["code"], lexeme),
-        }
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Code),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["code"])),
+        };
 
         let block = Block::parse(lexer, None)?;
 
-        Ok(Self { block })
+        Ok(Self { location, block })
     }
 }
 
@@ -44,3 +55,9 @@ where
         Ok(())
     }
 }
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "code {}", self.block)
+    }
+}
@@ -4,12 +4,22 @@
 
 pub mod name;
 
+use std::fmt;
+
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
+use num::ToPrimitive;
 
+use crate::yul::analyzer::error::Error as AnalyzerError;
+use crate::yul::lexer::lexeme::literal::Literal as LexicalLiteral;
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
+use crate::yul::parser::r#type::Type;
+use crate::yul::parser::statement::expression::literal::Literal;
 use crate::yul::parser::statement::expression::Expression;
 
 use self::name::Name;
@@ -19,49 +29,105 @@ use self::name::Name;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct FunctionCall {
+    /// The location.
+    pub location: Location,
     /// The function name.
     pub name: Name,
     /// The function arguments expression list.
     pub arguments: Vec<Expression>,
+    /// The unique name of the called function in the LLVM IR, if it has been mangled.
+    pub mangled_name: Option<String>,
+    /// The contents of the data section referenced by `datasize` or `dataoffset`, if any.
+    pub data: Option<Vec<u8>>,
+    /// Whether the stubbed builtin is compiled to a revert, as set in the trap mode.
+    pub is_trapped: bool,
 }
 
 impl FunctionCall {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
-
-        let name = match lexeme {
-            Lexeme::Identifier(identifier) => Name::from(identifier.as_str()),
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{identifier}"], lexeme);
-            }
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let (location, name) = match token {
+            Token {
+                location,
+                lexeme: Lexeme::Identifier(identifier),
+                ..
+            } => (location, Name::from(identifier.as_str())),
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["{identifier}"])),
         };
 
         let mut arguments = Vec::new();
         loop {
             let argument = match lexer.next()? {
-                Lexeme::Symbol(Symbol::ParenthesisRight) => break,
-                lexeme => Expression::parse(lexer, Some(lexeme))?,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => break,
+                token => Expression::parse(lexer, Some(token))?,
             };
 
             arguments.push(argument);
 
-            match lexer.peek()? {
-                Lexeme::Symbol(Symbol::Comma) => {
-                    lexer.next()?;
-                    continue;
-                }
-                Lexeme::Symbol(Symbol::ParenthesisRight) => {
-                    lexer.next()?;
-                    break;
-                }
-                _ => break,
+            match lexer.next()? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => continue,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => break,
+                token => anyhow::bail!(ParserError::invalid_token(token, vec![",", ")"])),
             }
         }
 
-        Ok(Self { name, arguments })
+        Ok(Self {
+            location,
+            name,
+            arguments,
+            mangled_name: None,
+            data: None,
+            is_trapped: false,
+        })
+    }
+
+    ///
+    /// Checks whether the call has no zkEVM equivalent, so it is compiled to a constant or a
+    /// no-op.
+    ///
+    /// Besides the stubbed builtins, these are the `datacopy` calls with a source other than a
+    /// `dataoffset` of a data section or a child object. The data sections are copied as
+    /// constants, and the child objects are copied as their bytecode hashes, which is how the
+    /// contracts are deployed by their factories.
+    ///
+    pub fn is_stubbed(&self) -> bool {
+        match self.name {
+            Name::DataCopy => !matches!(
+                self.arguments.get(1),
+                Some(Expression::FunctionCall(FunctionCall {
+                    name: Name::DataOffset,
+                    ..
+                }))
+            ),
+            ref name => name.is_stubbed(),
+        }
+    }
+
+    ///
+    /// Checks whether the call is a `datacopy` of a data section.
+    ///
+    fn is_data_section_copy(&self) -> bool {
+        matches!(
+            self.arguments.get(1),
+            Some(Expression::FunctionCall(FunctionCall {
+                name: Name::DataOffset,
+                data: Some(_),
+                ..
+            }))
+        )
     }
 
     ///
@@ -76,16 +142,44 @@ impl FunctionCall {
     {
         match self.name {
             Name::UserDefined(name) => {
+                let llvm_name = self.mangled_name.as_deref().unwrap_or(name.as_str());
+                let function = match context.functions.get(llvm_name).cloned() {
+                    Some(function) => function,
+                    None => anyhow::bail!(AnalyzerError::UndeclaredFunction {
+                        location: self.location,
+                        name,
+                    }),
+                };
+
+                let parameter_offset = match function.r#return {
+                    Some(compiler_llvm_context::FunctionReturn::Compound { .. }) => 1,
+                    _ => 0,
+                };
+                let parameter_count = function.value.count_params() as usize - parameter_offset;
+                if self.arguments.len() != parameter_count {
+                    anyhow::bail!(AnalyzerError::ArgumentCount {
+                        location: self.location,
+                        name,
+                        expected: parameter_count,
+                        found: self.arguments.len(),
+                    });
+                }
+
                 let mut values = Vec::with_capacity(self.arguments.len());
-                for argument in self.arguments.into_iter() {
-                    let value = argument.into_llvm(context)?.expect("Always exists").value;
-                    values.push(value);
+                for (index, argument) in self.arguments.into_iter().enumerate() {
+                    let r#type = argument.yul_type();
+                    let value = Self::argument_into_llvm(argument, context)?
+                        .value
+                        .into_int_value();
+                    let parameter_type = function
+                        .value
+                        .get_nth_param((index + parameter_offset) as u32)
+                        .expect("Always exists")
+                        .get_type()
+                        .into_int_type();
+                    let value = r#type.convert_llvm(context, value, parameter_type);
+                    values.push(value.as_basic_value_enum());
                 }
-                let function = context
-                    .functions
-                    .get(name.as_str())
-                    .cloned()
-                    .unwrap_or_else(|| panic!("Undeclared function {}", name));
 
                 if let Some(compiler_llvm_context::FunctionReturn::Compound { size, .. }) =
                     function.r#return
@@ -119,6 +213,33 @@ impl FunctionCall {
                 }
             }
 
+            Name::Conversion(r#type) => {
+                if self.arguments.len() != 1 {
+                    anyhow::bail!(AnalyzerError::ArgumentCount {
+                        location: self.location,
+                        name: Name::Conversion(r#type).to_string(),
+                        expected: 1,
+                        found: self.arguments.len(),
+                    });
+                }
+                let argument = self.arguments.remove(0);
+                let source_type = argument.yul_type();
+                let value = Self::argument_into_llvm(argument, context)?
+                    .value
+                    .into_int_value();
+
+                let value = match r#type {
+                    Type::Bool => context.builder().build_int_compare(
+                        inkwell::IntPredicate::NE,
+                        value,
+                        value.get_type().const_zero(),
+                        "conversion_to_bool",
+                    ),
+                    r#type => source_type.convert_llvm(context, value, r#type.into_llvm(context)),
+                };
+                Ok(Some(value.as_basic_value_enum()))
+            }
+
             Name::Add => {
                 let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
                 compiler_llvm_context::arithmetic::addition(context, arguments)
@@ -278,6 +399,15 @@ impl FunctionCall {
                 let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
                 compiler_llvm_context::memory::store_byte(context, arguments)
             }
+            Name::MCopy => {
+                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                crate::environment::memory_copy(
+                    context,
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                )
+            }
 
             Name::SLoad => {
                 let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
@@ -287,6 +417,18 @@ impl FunctionCall {
                 let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
                 compiler_llvm_context::storage::store(context, arguments)
             }
+            Name::TLoad => {
+                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                crate::environment::transient_storage::load(context, arguments[0].into_int_value())
+            }
+            Name::TStore => {
+                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                crate::environment::transient_storage::store(
+                    context,
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                )
+            }
             Name::LoadImmutable => {
                 let mut arguments = self.pop_arguments::<D, 1>(context)?;
                 let key = arguments[0]
@@ -314,14 +456,19 @@ impl FunctionCall {
                 let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
                 compiler_llvm_context::calldata::copy(context, arguments)
             }
-            Name::CodeSize => compiler_llvm_context::calldata::size(context),
+            Name::CodeSize => crate::environment::code_size(context),
             Name::CodeCopy => {
                 let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
-                compiler_llvm_context::calldata::copy(context, arguments)
+                crate::environment::code_copy(
+                    context,
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                )
             }
             Name::ExtCodeSize => {
-                let _arguments = self.pop_arguments_llvm::<D, 1>(context)?;
-                Ok(Some(context.field_const(0xffff).as_basic_value_enum()))
+                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                crate::environment::ext_code_size(context, arguments[0].into_int_value())
             }
             Name::ReturnDataSize => compiler_llvm_context::return_data::size(context),
             Name::ReturnDataCopy => {
@@ -421,6 +568,9 @@ impl FunctionCall {
             }
             Name::CallCode => {
                 let _arguments = self.pop_arguments_llvm::<D, 7>(context)?;
+                if self.is_trapped {
+                    crate::environment::trap::trap(context, "CALLCODE")?;
+                }
                 Ok(Some(context.field_const(0).as_basic_value_enum()))
             }
             Name::StaticCall => {
@@ -489,6 +639,12 @@ impl FunctionCall {
                     Some(salt),
                 )
             }
+            Name::DataOffset if self.data.is_some() => {
+                anyhow::bail!(
+                    "{} The `dataoffset` of a data section is only supported as the `datacopy` source offset",
+                    self.location
+                );
+            }
             Name::DataOffset => {
                 let mut arguments = self.pop_arguments::<D, 1>(context)?;
                 let identifier = arguments[0]
@@ -497,6 +653,10 @@ impl FunctionCall {
                     .ok_or_else(|| anyhow::anyhow!("`dataoffset` object identifier is missing"))?;
                 compiler_llvm_context::create::contract_hash(context, identifier)
             }
+            Name::DataSize if self.data.is_some() => {
+                let size = self.data.as_ref().map(Vec::len).unwrap_or_default();
+                Ok(Some(context.field_const(size as u64).as_basic_value_enum()))
+            }
             Name::DataSize => {
                 let mut arguments = self.pop_arguments::<D, 1>(context)?;
                 let identifier = arguments[0]
@@ -506,7 +666,55 @@ impl FunctionCall {
                 compiler_llvm_context::create::contract_hash_size(context, identifier)
             }
             Name::DataCopy => {
+                if self.is_data_section_copy() {
+                    let size = match self.arguments.get(2) {
+                        Some(Expression::Literal(Literal {
+                            inner: LexicalLiteral::Integer(ref integer),
+                            ..
+                        })) => integer.to_biguint().to_usize(),
+                        Some(Expression::FunctionCall(FunctionCall {
+                            name: Name::DataSize,
+                            data: Some(ref data),
+                            ..
+                        })) => Some(data.len()),
+                        _ => None,
+                    }
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "{} The `datacopy` size of a data section must be a literal or a `datasize`",
+                            self.location
+                        )
+                    })?;
+                    self.arguments.remove(2);
+
+                    let data = match self.arguments.remove(1) {
+                        Expression::FunctionCall(FunctionCall {
+                            data: Some(data), ..
+                        }) => data,
+                        _ => unreachable!(),
+                    };
+                    if size > data.len() {
+                        anyhow::bail!(
+                            "{} The `datacopy` size {} exceeds the data section size {}",
+                            self.location,
+                            size,
+                            data.len()
+                        );
+                    }
+
+                    let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                    crate::environment::data_copy(
+                        context,
+                        arguments[0].into_int_value(),
+                        &data[..size],
+                    )?;
+                    return Ok(None);
+                }
+
                 let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                if self.is_trapped {
+                    crate::environment::trap::trap(context, "DATACOPY")?;
+                }
                 compiler_llvm_context::memory::store(context, [arguments[0], arguments[1]])
             }
 
@@ -549,38 +757,104 @@ impl FunctionCall {
                 compiler_common::ContextValue::GasLeft,
             ),
 
-            Name::GasLimit => Ok(Some(
-                context.field_const(u32::MAX as u64).as_basic_value_enum(),
-            )),
-            Name::GasPrice => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            Name::CallValue => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            Name::MSize => Ok(Some(
-                context
-                    .field_const(((1 << 16) * compiler_common::SIZE_FIELD) as u64)
-                    .as_basic_value_enum(),
-            )),
-            Name::Origin => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            Name::ChainId => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            Name::BlockHash => Ok(Some(context.field_const(0).as_basic_value_enum())),
-
-            Name::Difficulty => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            Name::Pc => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            Name::Balance => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            Name::SelfBalance => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            Name::CoinBase => Ok(Some(context.field_const(0).as_basic_value_enum())),
-            Name::BaseFee => Ok(Some(context.field_const(0).as_basic_value_enum())),
+            Name::GasLimit => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::GasLimit,
+            ),
+            Name::GasPrice => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::GasPrice,
+            ),
+            Name::CallValue => crate::environment::call_value(context),
+            Name::MSize => {
+                if self.is_trapped {
+                    crate::environment::trap::trap(context, "MSIZE")?;
+                }
+                Ok(Some(
+                    context
+                        .field_const(((1 << 16) * compiler_common::SIZE_FIELD) as u64)
+                        .as_basic_value_enum(),
+                ))
+            }
+            Name::Origin => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::Origin,
+            ),
+            Name::ChainId => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::ChainId,
+            ),
+            Name::BlockHash => {
+                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                crate::environment::block_hash(context, arguments[0].into_int_value())
+            }
+
+            Name::Difficulty | Name::PrevRandao => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::Difficulty,
+            ),
+            Name::Pc => {
+                if self.is_trapped {
+                    crate::environment::trap::trap(context, "PC")?;
+                }
+                Ok(Some(context.field_const(0).as_basic_value_enum()))
+            }
+            Name::Balance => {
+                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                crate::environment::balance(context, arguments[0].into_int_value())
+            }
+            Name::SelfBalance => crate::environment::self_balance(context),
+            Name::CoinBase => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::CoinBase,
+            ),
+            Name::BaseFee => crate::environment::system_context(
+                context,
+                crate::environment::system_context_value::SystemContextValue::BaseFee,
+            ),
+            Name::BlobHash => {
+                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                crate::environment::blob_hash(context, arguments[0].into_int_value())
+            }
+            Name::BlobBaseFee => {
+                if self.is_trapped {
+                    crate::environment::trap::trap(context, "BLOBBASEFEE")?;
+                }
+                // The minimal blob base fee, as there is no blob fee market in the zkEVM.
+                Ok(Some(context.field_const(1).as_basic_value_enum()))
+            }
             Name::ExtCodeCopy => {
-                let _arguments = self.pop_arguments_llvm::<D, 4>(context)?;
-                Ok(None)
+                let arguments = self.pop_arguments_llvm::<D, 4>(context)?;
+                if self.is_trapped {
+                    crate::environment::trap::trap(context, "EXTCODECOPY")?;
+                    Ok(None)
+                } else {
+                    crate::environment::ext_code_copy(
+                        context,
+                        arguments[1].into_int_value(),
+                        arguments[3].into_int_value(),
+                    )
+                }
             }
             Name::ExtCodeHash => {
-                let _arguments = self.pop_arguments_llvm::<D, 1>(context)?;
-                Ok(Some(context.field_const(0).as_basic_value_enum()))
+                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                crate::environment::ext_code_hash(context, arguments[0].into_int_value())
             }
             Name::SelfDestruct => {
                 let _arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                if self.is_trapped {
+                    crate::environment::trap::trap(context, "SELFDESTRUCT")?;
+                }
                 Ok(None)
             }
+
+            name @ Name::Verbatim { .. } => {
+                anyhow::bail!(
+                    "{} The `{}` builtin is not supported, as the EVM bytecode cannot be injected into the zkEVM code",
+                    self.location,
+                    name
+                );
+            }
         }
     }
 
@@ -594,17 +868,16 @@ impl FunctionCall {
     where
         D: compiler_llvm_context::Dependency,
     {
-        let mut arguments = Vec::with_capacity(N);
-        for expression in self.arguments.drain(0..N) {
-            arguments.push(expression.into_llvm(context)?.expect("Always exists").value);
-        }
+        let arguments = self.pop_arguments::<D, N>(context)?;
 
-        Ok(arguments.try_into().expect("Always successful"))
+        Ok(arguments.map(|argument| argument.value))
     }
 
     ///
     /// Pops the specified number of arguments.
     ///
+    /// The builtin functions operate on the field values, so the narrower arguments are extended.
+    ///
     fn pop_arguments<'ctx, D, const N: usize>(
         &mut self,
         context: &mut compiler_llvm_context::Context<'ctx, D>,
@@ -612,11 +885,63 @@ impl FunctionCall {
     where
         D: compiler_llvm_context::Dependency,
     {
+        if self.arguments.len() != N {
+            anyhow::bail!(AnalyzerError::ArgumentCount {
+                location: self.location.clone(),
+                name: self.name.to_string(),
+                expected: N,
+                found: self.arguments.len(),
+            });
+        }
+
         let mut arguments = Vec::with_capacity(N);
-        for expression in self.arguments.drain(0..N) {
-            arguments.push(expression.into_llvm(context)?.expect("Always exists"));
+        for expression in self.arguments.drain(..) {
+            let r#type = expression.yul_type();
+            let mut argument = Self::argument_into_llvm(expression, context)?;
+            argument.value = r#type
+                .convert_llvm(
+                    context,
+                    argument.value.into_int_value(),
+                    context.field_type(),
+                )
+                .as_basic_value_enum();
+            arguments.push(argument);
         }
 
         Ok(arguments.try_into().expect("Always successful"))
     }
+
+    ///
+    /// Converts the argument expression into an LLVM value, checking that it returns one.
+    ///
+    fn argument_into_llvm<'ctx, D>(
+        expression: Expression,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+    ) -> anyhow::Result<compiler_llvm_context::Argument<'ctx>>
+    where
+        D: compiler_llvm_context::Dependency,
+    {
+        let location = expression.location();
+        match expression.into_llvm(context)? {
+            Some(argument) => Ok(argument),
+            None => anyhow::bail!(AnalyzerError::ValueCount {
+                location,
+                expected: 1,
+                found: 0,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for FunctionCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (index, argument) in self.arguments.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", argument)?;
+        }
+        write!(f, ")")
+    }
 }
@@ -2,6 +2,12 @@
 //! The function name.
 //!
 
+use std::fmt;
+
+use crate::evm_version::EVMVersion;
+use crate::yul::lexer::lexeme::keyword::Keyword;
+use crate::yul::parser::r#type::Type;
+
 ///
 /// The function name.
 ///
@@ -9,6 +15,10 @@
 pub enum Name {
     /// The user-defined function.
     UserDefined(String),
+    /// The explicit conversion `to_{type}` to a supported type, like `to_uint8`, or `to_bool`.
+    ///
+    /// A user-defined function of the same name shadows it, which is resolved by the mangler.
+    Conversion(Type),
 
     /// `x + y`
     Add,
@@ -75,11 +85,17 @@ pub enum Name {
     MStore,
     /// `mem[p] := v & 0xff` (only modifies a single byte)
     MStore8,
+    /// copy `s` bytes from mem at position `f` to mem at position `t`
+    MCopy,
 
     /// `storage[p]`
     SLoad,
     /// `storage[p] := v`
     SStore,
+    /// `transient_storage[p]`
+    TLoad,
+    /// `transient_storage[p] := v`
+    TStore,
     /// `loadimmutable` storage read
     LoadImmutable,
     /// `setimmutable` storage write
@@ -182,6 +198,8 @@ pub enum Name {
 
     /// difficulty of the current block
     Difficulty,
+    /// randomness provided by the beacon chain
+    PrevRandao,
     /// current position in code
     Pc,
     /// wei balance at address `a`
@@ -192,16 +210,283 @@ pub enum Name {
     CoinBase,
     /// the base fee
     BaseFee,
+    /// versioned hash of transaction's `i`-th blob
+    BlobHash,
+    /// the blob base fee
+    BlobBaseFee,
     /// like `codecopy(t, f, s)` but take code at address `a`
     ExtCodeCopy,
     /// code hash of address `a`
     ExtCodeHash,
     /// end execution, destroy current contract and send funds to `a`
     SelfDestruct,
+
+    /// `verbatim_<n>i_<m>o(data, ...)` inserts the `data` bytecode, taking `n` and returning `m`
+    /// stack values
+    Verbatim {
+        /// The number of the stack input values.
+        input_size: usize,
+        /// The number of the stack output values.
+        output_size: usize,
+    },
+}
+
+impl Name {
+    ///
+    /// Returns the number of the builtin function arguments and return values.
+    ///
+    /// Returns `None` for the user-defined functions, whose signatures are only known from their
+    /// definitions.
+    ///
+    pub fn arity(&self) -> Option<(usize, usize)> {
+        Some(match self {
+            Self::UserDefined(_) => return None,
+            Self::Conversion(_) => (1, 1),
+
+            Self::Add => (2, 1),
+            Self::Sub => (2, 1),
+            Self::Mul => (2, 1),
+            Self::Div => (2, 1),
+            Self::Mod => (2, 1),
+            Self::Sdiv => (2, 1),
+            Self::Smod => (2, 1),
+
+            Self::Lt => (2, 1),
+            Self::Gt => (2, 1),
+            Self::Eq => (2, 1),
+            Self::IsZero => (1, 1),
+            Self::Slt => (2, 1),
+            Self::Sgt => (2, 1),
+
+            Self::Or => (2, 1),
+            Self::Xor => (2, 1),
+            Self::Not => (1, 1),
+            Self::And => (2, 1),
+            Self::Shl => (2, 1),
+            Self::Shr => (2, 1),
+            Self::Sar => (2, 1),
+            Self::Byte => (2, 1),
+            Self::Pop => (1, 0),
+
+            Self::AddMod => (3, 1),
+            Self::MulMod => (3, 1),
+            Self::Exp => (2, 1),
+            Self::SignExtend => (2, 1),
+
+            Self::Keccak256 => (2, 1),
+
+            Self::MLoad => (1, 1),
+            Self::MStore => (2, 0),
+            Self::MStore8 => (2, 0),
+            Self::MCopy => (3, 0),
+
+            Self::SLoad => (1, 1),
+            Self::SStore => (2, 0),
+            Self::TLoad => (1, 1),
+            Self::TStore => (2, 0),
+            Self::LoadImmutable => (1, 1),
+            Self::SetImmutable => (3, 0),
+
+            Self::CallDataLoad => (1, 1),
+            Self::CallDataSize => (0, 1),
+            Self::CallDataCopy => (3, 0),
+            Self::CodeSize => (0, 1),
+            Self::CodeCopy => (3, 0),
+            Self::ExtCodeSize => (1, 1),
+            Self::ReturnDataSize => (0, 1),
+            Self::ReturnDataCopy => (3, 0),
+
+            Self::Return => (2, 0),
+            Self::Revert => (2, 0),
+            Self::Stop => (0, 0),
+            Self::Invalid => (0, 0),
+
+            Self::Log0 => (2, 0),
+            Self::Log1 => (3, 0),
+            Self::Log2 => (4, 0),
+            Self::Log3 => (5, 0),
+            Self::Log4 => (6, 0),
+
+            Self::Call => (7, 1),
+            Self::CallCode => (7, 1),
+            Self::DelegateCall => (6, 1),
+            Self::StaticCall => (6, 1),
+
+            Self::Create => (3, 1),
+            Self::Create2 => (4, 1),
+            Self::DataSize => (1, 1),
+            Self::DataCopy => (3, 0),
+            Self::DataOffset => (1, 1),
+
+            Self::LinkerSymbol => (1, 1),
+            Self::MemoryGuard => (1, 1),
+
+            Self::Address => (0, 1),
+            Self::Caller => (0, 1),
+            Self::Timestamp => (0, 1),
+            Self::Number => (0, 1),
+            Self::Gas => (0, 1),
+            Self::GasLimit => (0, 1),
+            Self::GasPrice => (0, 1),
+            Self::CallValue => (0, 1),
+            Self::MSize => (0, 1),
+            Self::Origin => (0, 1),
+            Self::ChainId => (0, 1),
+            Self::BlockHash => (1, 1),
+            Self::Difficulty => (0, 1),
+            Self::PrevRandao => (0, 1),
+            Self::Pc => (0, 1),
+            Self::Balance => (1, 1),
+            Self::SelfBalance => (0, 1),
+            Self::CoinBase => (0, 1),
+            Self::BaseFee => (0, 1),
+            Self::BlobHash => (1, 1),
+            Self::BlobBaseFee => (0, 1),
+            Self::ExtCodeCopy => (4, 0),
+            Self::ExtCodeHash => (1, 1),
+            Self::SelfDestruct => (1, 0),
+
+            Self::Verbatim {
+                input_size,
+                output_size,
+            } => (input_size + 1, *output_size),
+        })
+    }
+
+    ///
+    /// Checks whether the builtin function computes its result from the arguments only, without
+    /// reading or changing the state, so its calls can be evaluated at compile time, or removed
+    /// if their results are unused.
+    ///
+    pub fn is_pure(&self) -> bool {
+        matches!(
+            self,
+            Self::Conversion(_)
+                | Self::Add
+                | Self::Sub
+                | Self::Mul
+                | Self::Div
+                | Self::Mod
+                | Self::Sdiv
+                | Self::Smod
+                | Self::Lt
+                | Self::Gt
+                | Self::Eq
+                | Self::IsZero
+                | Self::Slt
+                | Self::Sgt
+                | Self::Or
+                | Self::Xor
+                | Self::Not
+                | Self::And
+                | Self::Shl
+                | Self::Shr
+                | Self::Sar
+                | Self::Byte
+                | Self::AddMod
+                | Self::MulMod
+                | Self::Exp
+                | Self::SignExtend
+        )
+    }
+
+    ///
+    /// Checks whether the builtin function has no zkEVM equivalent, so it is compiled to a
+    /// constant or a no-op.
+    ///
+    pub fn is_stubbed(&self) -> bool {
+        matches!(
+            self,
+            Self::CallCode
+                | Self::Pc
+                | Self::MSize
+                | Self::SelfDestruct
+                | Self::ExtCodeCopy
+                | Self::BlobBaseFee
+        )
+    }
+
+    ///
+    /// Checks whether the builtin function reads the code of another account, which differs
+    /// between EVM and zkEVM.
+    ///
+    pub fn is_ext_code(&self) -> bool {
+        matches!(
+            self,
+            Self::ExtCodeSize | Self::ExtCodeHash | Self::ExtCodeCopy
+        )
+    }
+
+    ///
+    /// Returns the EVM version introducing the builtin function, if it is newer than the oldest
+    /// supported one.
+    ///
+    pub fn evm_version(&self) -> Option<EVMVersion> {
+        match self {
+            Self::PrevRandao => Some(EVMVersion::Paris),
+            Self::TLoad | Self::TStore | Self::MCopy | Self::BlobHash | Self::BlobBaseFee => {
+                Some(EVMVersion::Cancun)
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Checks whether the builtin function never returns control to the code after its call.
+    ///
+    pub fn is_terminating(&self) -> bool {
+        matches!(
+            self,
+            Self::Return | Self::Revert | Self::Stop | Self::Invalid
+        )
+    }
+
+    ///
+    /// Parses the `verbatim_<n>i_<m>o` builtin name, where the numbers of the input and output
+    /// values are decimal and cannot exceed 99, like in solc.
+    ///
+    fn parse_verbatim(input: &str) -> Option<Self> {
+        let (input_size, output_size) = input
+            .strip_prefix("verbatim_")?
+            .strip_suffix('o')?
+            .split_once("i_")?;
+        let parse_size = |size: &str| -> Option<usize> {
+            if size.is_empty()
+                || size.len() > 2
+                || (size.len() > 1 && size.starts_with('0'))
+                || !size.bytes().all(|byte| byte.is_ascii_digit())
+            {
+                return None;
+            }
+            size.parse().ok()
+        };
+
+        Some(Self::Verbatim {
+            input_size: parse_size(input_size)?,
+            output_size: parse_size(output_size)?,
+        })
+    }
 }
 
 impl From<&str> for Name {
     fn from(input: &str) -> Self {
+        let conversion = input
+            .strip_prefix("to_")
+            .and_then(|input| Keyword::try_from(input).ok())
+            .and_then(|keyword| match keyword {
+                Keyword::Bool => Some(Type::Bool),
+                Keyword::Int(bitlength) => Some(Type::Int(bitlength)),
+                Keyword::Uint(bitlength) => Some(Type::UInt(bitlength)),
+                _ => None,
+            })
+            .filter(Type::is_supported);
+        if let Some(r#type) = conversion {
+            return Self::Conversion(r#type);
+        }
+        if let Some(verbatim) = Self::parse_verbatim(input) {
+            return verbatim;
+        }
+
         match input {
             "add" => Self::Add,
             "sub" => Self::Sub,
@@ -238,9 +523,12 @@ impl From<&str> for Name {
             "mload" => Self::MLoad,
             "mstore" => Self::MStore,
             "mstore8" => Self::MStore8,
+            "mcopy" => Self::MCopy,
 
             "sload" => Self::SLoad,
             "sstore" => Self::SStore,
+            "tload" => Self::TLoad,
+            "tstore" => Self::TStore,
             "loadimmutable" => Self::LoadImmutable,
             "setimmutable" => Self::SetImmutable,
 
@@ -294,11 +582,14 @@ impl From<&str> for Name {
             "blockhash" => Self::BlockHash,
 
             "difficulty" => Self::Difficulty,
+            "prevrandao" => Self::PrevRandao,
             "pc" => Self::Pc,
             "balance" => Self::Balance,
             "selfbalance" => Self::SelfBalance,
             "coinbase" => Self::CoinBase,
             "basefee" => Self::BaseFee,
+            "blobhash" => Self::BlobHash,
+            "blobbasefee" => Self::BlobBaseFee,
             "extcodecopy" => Self::ExtCodeCopy,
             "extcodehash" => Self::ExtCodeHash,
             "selfdestruct" => Self::SelfDestruct,
@@ -307,3 +598,106 @@ impl From<&str> for Name {
         }
     }
 }
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UserDefined(inner) => write!(f, "{}", inner),
+            Self::Conversion(r#type) => write!(f, "to_{}", r#type),
+            Self::Add => write!(f, "add"),
+            Self::Sub => write!(f, "sub"),
+            Self::Mul => write!(f, "mul"),
+            Self::Div => write!(f, "div"),
+            Self::Mod => write!(f, "mod"),
+            Self::Sdiv => write!(f, "sdiv"),
+            Self::Smod => write!(f, "smod"),
+            Self::Lt => write!(f, "lt"),
+            Self::Gt => write!(f, "gt"),
+            Self::Eq => write!(f, "eq"),
+            Self::IsZero => write!(f, "iszero"),
+            Self::Slt => write!(f, "slt"),
+            Self::Sgt => write!(f, "sgt"),
+            Self::Or => write!(f, "or"),
+            Self::Xor => write!(f, "xor"),
+            Self::Not => write!(f, "not"),
+            Self::And => write!(f, "and"),
+            Self::Shl => write!(f, "shl"),
+            Self::Shr => write!(f, "shr"),
+            Self::Sar => write!(f, "sar"),
+            Self::Byte => write!(f, "byte"),
+            Self::Pop => write!(f, "pop"),
+            Self::AddMod => write!(f, "addmod"),
+            Self::MulMod => write!(f, "mulmod"),
+            Self::Exp => write!(f, "exp"),
+            Self::SignExtend => write!(f, "signextend"),
+            Self::Keccak256 => write!(f, "keccak256"),
+            Self::MLoad => write!(f, "mload"),
+            Self::MStore => write!(f, "mstore"),
+            Self::MStore8 => write!(f, "mstore8"),
+            Self::MCopy => write!(f, "mcopy"),
+            Self::SLoad => write!(f, "sload"),
+            Self::SStore => write!(f, "sstore"),
+            Self::TLoad => write!(f, "tload"),
+            Self::TStore => write!(f, "tstore"),
+            Self::LoadImmutable => write!(f, "loadimmutable"),
+            Self::SetImmutable => write!(f, "setimmutable"),
+            Self::CallDataLoad => write!(f, "calldataload"),
+            Self::CallDataSize => write!(f, "calldatasize"),
+            Self::CallDataCopy => write!(f, "calldatacopy"),
+            Self::CodeSize => write!(f, "codesize"),
+            Self::CodeCopy => write!(f, "codecopy"),
+            Self::ExtCodeSize => write!(f, "extcodesize"),
+            Self::ReturnDataSize => write!(f, "returndatasize"),
+            Self::ReturnDataCopy => write!(f, "returndatacopy"),
+            Self::Return => write!(f, "return"),
+            Self::Revert => write!(f, "revert"),
+            Self::Log0 => write!(f, "log0"),
+            Self::Log1 => write!(f, "log1"),
+            Self::Log2 => write!(f, "log2"),
+            Self::Log3 => write!(f, "log3"),
+            Self::Log4 => write!(f, "log4"),
+            Self::Call => write!(f, "call"),
+            Self::CallCode => write!(f, "callcode"),
+            Self::DelegateCall => write!(f, "delegatecall"),
+            Self::StaticCall => write!(f, "staticcall"),
+            Self::Create => write!(f, "create"),
+            Self::Create2 => write!(f, "create2"),
+            Self::DataSize => write!(f, "datasize"),
+            Self::DataOffset => write!(f, "dataoffset"),
+            Self::DataCopy => write!(f, "datacopy"),
+            Self::Stop => write!(f, "stop"),
+            Self::Invalid => write!(f, "invalid"),
+            Self::LinkerSymbol => write!(f, "linkersymbol"),
+            Self::MemoryGuard => write!(f, "memoryguard"),
+            Self::Address => write!(f, "address"),
+            Self::Caller => write!(f, "caller"),
+            Self::Timestamp => write!(f, "timestamp"),
+            Self::Number => write!(f, "number"),
+            Self::Gas => write!(f, "gas"),
+            Self::GasLimit => write!(f, "gaslimit"),
+            Self::GasPrice => write!(f, "gasprice"),
+            Self::CallValue => write!(f, "callvalue"),
+            Self::MSize => write!(f, "msize"),
+            Self::Origin => write!(f, "origin"),
+            Self::ChainId => write!(f, "chainid"),
+            Self::BlockHash => write!(f, "blockhash"),
+            Self::Difficulty => write!(f, "difficulty"),
+            Self::PrevRandao => write!(f, "prevrandao"),
+            Self::Pc => write!(f, "pc"),
+            Self::Balance => write!(f, "balance"),
+            Self::SelfBalance => write!(f, "selfbalance"),
+            Self::CoinBase => write!(f, "coinbase"),
+            Self::BaseFee => write!(f, "basefee"),
+            Self::BlobHash => write!(f, "blobhash"),
+            Self::BlobBaseFee => write!(f, "blobbasefee"),
+            Self::ExtCodeCopy => write!(f, "extcodecopy"),
+            Self::ExtCodeHash => write!(f, "extcodehash"),
+            Self::SelfDestruct => write!(f, "selfdestruct"),
+
+            Self::Verbatim {
+                input_size,
+                output_size,
+            } => write!(f, "verbatim_{}i_{}o", input_size, output_size),
+        }
+    }
+}
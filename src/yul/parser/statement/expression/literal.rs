@@ -2,6 +2,8 @@
 //! The YUL source code literal.
 //!
 
+use std::fmt;
+
 use inkwell::values::BasicValue;
 
 use crate::yul::lexer::lexeme::literal::boolean::Boolean as BooleanLiteral;
@@ -9,7 +11,10 @@ use crate::yul::lexer::lexeme::literal::integer::Integer as IntegerLiteral;
 use crate::yul::lexer::lexeme::literal::Literal as LexicalLiteral;
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::r#type::Type;
 
 ///
@@ -17,6 +22,8 @@ use crate::yul::parser::r#type::Type;
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Literal {
+    /// The location.
+    pub location: Location,
     /// The lexical literal.
     pub inner: LexicalLiteral,
     /// The type, if it has been explicitly specified.
@@ -27,18 +34,23 @@ impl Literal {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let literal = match lexeme {
-            Lexeme::Literal(literal) => literal,
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{literal}"], lexeme);
-            }
+        let (location, literal) = match token {
+            Token {
+                location,
+                lexeme: Lexeme::Literal(literal),
+                ..
+            } => (location, literal),
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["{literal}"])),
         };
 
         let yul_type = match lexer.peek()? {
-            Lexeme::Symbol(Symbol::Colon) => {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Colon),
+                ..
+            } => {
                 lexer.next()?;
                 Some(Type::parse(lexer, None)?)
             }
@@ -46,11 +58,40 @@ impl Literal {
         };
 
         Ok(Self {
+            location,
             inner: literal,
             yul_type,
         })
     }
 
+    ///
+    /// Checks whether the literal value is representable by the `type`.
+    ///
+    /// The boolean literals fit all types, as `true` and `false` are `1` and `0` for the
+    /// integers. The integer literals must be within the type range, but the hexadecimal ones
+    /// may also specify the full bit pattern of a signed integer, like `0xff` for `int8`. The
+    /// string literals fit only the 256-bit integer types.
+    ///
+    pub fn fits(&self, r#type: &Type) -> bool {
+        let bitlength = match r#type {
+            Type::Bool => return matches!(self.inner, LexicalLiteral::Boolean(_)),
+            Type::Int(bitlength) | Type::UInt(bitlength) => *bitlength,
+            Type::Custom(_) => return false,
+        };
+
+        let value_bitlength = match self.inner {
+            LexicalLiteral::Boolean(_) => return true,
+            LexicalLiteral::Integer(ref inner) => inner.to_biguint().bits() as usize,
+            LexicalLiteral::String(_) => return bitlength == compiler_common::BITLENGTH_FIELD,
+        };
+        match (r#type, &self.inner) {
+            (Type::Int(_), LexicalLiteral::Integer(IntegerLiteral::Decimal { .. })) => {
+                value_bitlength < bitlength
+            }
+            _ => value_bitlength <= bitlength,
+        }
+    }
+
     ///
     /// Converts the literal into its LLVM representation.
     ///
@@ -94,46 +135,23 @@ impl Literal {
                 compiler_llvm_context::Argument::new(value)
             }
             LexicalLiteral::String(inner) => {
-                let string = inner.to_string();
                 let r#type = self.yul_type.unwrap_or_default().into_llvm(context);
 
-                let mut hex_string = String::with_capacity(compiler_common::SIZE_FIELD * 2);
-                let mut index = 0;
-                loop {
-                    if index >= string.len() {
-                        break;
-                    }
-
-                    if string[index..].starts_with("\\x") {
-                        hex_string.push_str(&string[index + 2..index + 4]);
-                        index += 4;
-                    } else if string[index..].starts_with("\\t") {
-                        hex_string.push_str("09");
-                        index += 2;
-                    } else if string[index..].starts_with("\\n") {
-                        hex_string.push_str("0a");
-                        index += 2;
-                    } else if string[index..].starts_with("\\r") {
-                        hex_string.push_str("0d");
-                        index += 2;
-                    } else {
-                        hex_string.push_str(format!("{:02x}", string.as_bytes()[index]).as_str());
-                        index += 1;
-                    }
-                }
-
-                if hex_string.len() > compiler_common::SIZE_FIELD * 2 {
+                if inner.bytes.len() > compiler_common::SIZE_FIELD {
                     return compiler_llvm_context::Argument::new_with_original(
                         r#type.const_zero().as_basic_value_enum(),
-                        string,
+                        inner.inner,
                     );
                 }
-                if string.len() < compiler_common::SIZE_FIELD {
-                    hex_string.push_str(
-                        "00".repeat(compiler_common::SIZE_FIELD - string.len())
-                            .as_str(),
-                    );
+
+                let mut hex_string = String::with_capacity(compiler_common::SIZE_FIELD * 2);
+                for byte in inner.bytes.iter() {
+                    hex_string.push_str(format!("{:02x}", byte).as_str());
                 }
+                hex_string.push_str(
+                    "00".repeat(compiler_common::SIZE_FIELD - inner.bytes.len())
+                        .as_str(),
+                );
 
                 let value = r#type
                     .const_int_from_string(
@@ -142,8 +160,24 @@ impl Literal {
                     )
                     .expect("The value is valid")
                     .as_basic_value_enum();
-                compiler_llvm_context::Argument::new_with_original(value, string)
+                compiler_llvm_context::Argument::new_with_original(value, inner.inner)
             }
         }
     }
 }
+
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.inner {
+            LexicalLiteral::String(ref inner) if inner.is_hexadecimal => {
+                write!(f, "hex\"{}\"", inner.inner)?
+            }
+            LexicalLiteral::String(ref inner) => write!(f, "\"{}\"", inner.inner)?,
+            ref inner => write!(f, "{}", inner)?,
+        }
+        if let Some(ref yul_type) = self.yul_type {
+            write!(f, ":{}", yul_type)?;
+        }
+        Ok(())
+    }
+}
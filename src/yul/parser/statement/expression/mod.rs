@@ -5,10 +5,18 @@
 pub mod function_call;
 pub mod literal;
 
+use std::fmt;
+
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::r#type::Type;
 
+use self::function_call::name::Name;
 use self::function_call::FunctionCall;
 use self::literal::Literal;
 
@@ -20,7 +28,7 @@ pub enum Expression {
     /// The function call subexpression.
     FunctionCall(FunctionCall),
     /// The identifier operand.
-    Identifier(String),
+    Identifier(Identifier),
     /// The literal operand.
     Literal(Literal),
 }
@@ -29,30 +37,63 @@ impl Expression {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let identifier = match lexeme {
-            Lexeme::Literal(_) => return Ok(Self::Literal(Literal::parse(lexer, Some(lexeme))?)),
-            Lexeme::Identifier(identifier) => identifier,
-            lexeme => {
-                anyhow::bail!(
-                    "Expected one of {:?}, found `{}`",
-                    ["{literal}", "{identifier}"],
-                    lexeme
-                );
-            }
+        let (location, identifier) = match token {
+            Token {
+                lexeme: Lexeme::Literal(_),
+                ..
+            } => return Ok(Self::Literal(Literal::parse(lexer, Some(token))?)),
+            Token {
+                ref location,
+                lexeme: Lexeme::Identifier(ref identifier),
+                ..
+            } => (location.clone(), identifier.to_owned()),
+            token => anyhow::bail!(ParserError::invalid_token(
+                token,
+                vec!["{literal}", "{identifier}"],
+            )),
         };
 
         match lexer.peek()? {
-            Lexeme::Symbol(Symbol::ParenthesisLeft) => {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                ..
+            } => {
                 lexer.next()?;
-                Ok(Self::FunctionCall(FunctionCall::parse(
-                    lexer,
-                    Some(Lexeme::Identifier(identifier)),
-                )?))
+                Ok(Self::FunctionCall(FunctionCall::parse(lexer, Some(token))?))
             }
-            _ => Ok(Self::Identifier(identifier)),
+            _ => Ok(Self::Identifier(Identifier::new(location, identifier))),
+        }
+    }
+
+    ///
+    /// Returns the expression location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::FunctionCall(inner) => inner.location.clone(),
+            Self::Identifier(inner) => inner.location.clone(),
+            Self::Literal(inner) => inner.location.clone(),
+        }
+    }
+
+    ///
+    /// Returns the type of the expression value.
+    ///
+    /// The identifier types are only known after the semantic analysis, and the untyped literals
+    /// and the builtin function results are the 256-bit unsigned integers.
+    ///
+    pub fn yul_type(&self) -> Type {
+        match self {
+            Self::FunctionCall(FunctionCall {
+                name: Name::Conversion(r#type),
+                ..
+            }) => r#type.to_owned(),
+            Self::FunctionCall(_) => Type::default(),
+            Self::Identifier(inner) => inner.yul_type.to_owned().unwrap_or_default(),
+            Self::Literal(inner) => inner.yul_type.to_owned().unwrap_or_default(),
         }
     }
 
@@ -70,7 +111,10 @@ impl Expression {
             Self::Literal(inner) => Ok(Some(inner.into_llvm(context))),
             Self::Identifier(inner) => Ok(Some(
                 context
-                    .build_load(context.function().stack[inner.as_str()], inner.as_str())
+                    .build_load(
+                        context.function().stack[inner.name.as_str()],
+                        inner.name.as_str(),
+                    )
                     .into(),
             )),
             Self::FunctionCall(inner) => Ok(inner
@@ -79,3 +123,13 @@ impl Expression {
         }
     }
 }
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FunctionCall(inner) => write!(f, "{}", inner),
+            Self::Identifier(inner) => write!(f, "{}", inner.name),
+            Self::Literal(inner) => write!(f, "{}", inner),
+        }
+    }
+}
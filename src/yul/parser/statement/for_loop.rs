@@ -2,8 +2,14 @@
 //! The for-loop statement.
 //!
 
+use std::fmt;
+
+use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::Expression;
 
@@ -12,6 +18,8 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct ForLoop {
+    /// The location.
+    pub location: Location,
     /// The index variables initialization block.
     pub initializer: Block,
     /// The continue condition block.
@@ -26,10 +34,19 @@ impl ForLoop {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::For),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["for"])),
+        };
 
-        let initializer = Block::parse(lexer, Some(lexeme))?;
+        let initializer = Block::parse(lexer, None)?;
 
         let condition = Expression::parse(lexer, None)?;
 
@@ -38,6 +55,7 @@ impl ForLoop {
         let body = Block::parse(lexer, None)?;
 
         Ok(Self {
+            location,
             initializer,
             condition,
             finalizer,
@@ -95,3 +113,13 @@ where
         Ok(())
     }
 }
+
+impl fmt::Display for ForLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "for {} {} {} {}",
+            self.initializer, self.condition, self.finalizer, self.body
+        )
+    }
+}
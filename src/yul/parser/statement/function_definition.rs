@@ -2,11 +2,17 @@
 //! The function definition statement.
 //!
 
+use std::fmt;
+
 use inkwell::types::BasicType;
 
+use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::identifier::Identifier;
 use crate::yul::parser::statement::block::Block;
 
@@ -15,6 +21,8 @@ use crate::yul::parser::statement::block::Block;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct FunctionDefinition {
+    /// The location.
+    pub location: Location,
     /// The function name.
     pub name: String,
     /// The function formal arguments.
@@ -23,54 +31,85 @@ pub struct FunctionDefinition {
     pub result: Vec<Identifier>,
     /// The function body block.
     pub body: Block,
+    /// The unique name used in the LLVM IR, if the function name clashes with another function.
+    pub mangled_name: Option<String>,
 }
 
 impl FunctionDefinition {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let name = match lexeme {
-            Lexeme::Identifier(name) => name,
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{identifier}"], lexeme);
-            }
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Function),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["function"])),
+        };
+
+        let name = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Identifier(name),
+                ..
+            } => name,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["{identifier}"])),
         };
 
         match lexer.next()? {
-            Lexeme::Symbol(Symbol::ParenthesisLeft) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["("], lexeme),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                ..
+            } => {}
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["("])),
         }
 
         let (arguments, next) = Identifier::parse_typed_list(lexer, None)?;
 
         match crate::yul::parser::take_or_next(next, lexer)? {
-            Lexeme::Symbol(Symbol::ParenthesisRight) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", [")"], lexeme),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                ..
+            } => {}
+            token => anyhow::bail!(ParserError::invalid_token(token, vec![")"])),
         }
 
         let (result, next) = match lexer.peek()? {
-            Lexeme::Symbol(Symbol::Arrow) => {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Arrow),
+                ..
+            } => {
                 lexer.next()?;
                 Identifier::parse_typed_list(lexer, None)?
             }
-            Lexeme::Symbol(Symbol::BracketCurlyLeft) => (vec![], None),
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["->", "{"], lexeme);
-            }
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                ..
+            } => (vec![], None),
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["->", "{"])),
         };
 
         let body = Block::parse(lexer, next)?;
 
         Ok(Self {
+            location,
             name,
             arguments,
             result,
             body,
+            mangled_name: None,
         })
     }
+
+    ///
+    /// Returns the function name used in the LLVM IR.
+    ///
+    pub fn llvm_name(&self) -> &str {
+        self.mangled_name.as_deref().unwrap_or(self.name.as_str())
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for FunctionDefinition
@@ -90,7 +129,7 @@ where
         let function_type = context.function_type(self.result.len(), argument_types);
 
         context.add_function(
-            self.name.as_str(),
+            self.llvm_name(),
             function_type,
             Some(inkwell::module::Linkage::Private),
         );
@@ -98,7 +137,7 @@ where
         if self.result.len() > 1 {
             let function = context
                 .functions
-                .get(self.name.as_str())
+                .get(self.llvm_name())
                 .cloned()
                 .ok_or_else(|| anyhow::anyhow!("Entry not found"))?;
             let pointer = function
@@ -116,60 +155,35 @@ where
         Ok(())
     }
 
-    fn into_llvm(mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         let function = context
             .functions
-            .get(self.name.as_str())
+            .get(self.llvm_name())
             .cloned()
             .expect("Function always exists");
         context.set_function(function.clone());
 
         context.set_basic_block(function.entry_block);
+        let mut results = Vec::with_capacity(self.result.len());
+        for identifier in self.result.into_iter() {
+            let r#type = identifier.yul_type.unwrap_or_default();
+            let pointer = context.build_alloca(
+                r#type.clone().into_llvm(context),
+                format!("{}_pointer", identifier.name).as_str(),
+            );
+            context.build_store(pointer, r#type.clone().into_llvm(context).const_zero());
+            context
+                .function_mut()
+                .stack
+                .insert(identifier.name, pointer);
+            results.push((r#type, pointer));
+        }
         let r#return = match function.r#return {
-            Some(r#return) => {
-                for (index, identifier) in self.result.into_iter().enumerate() {
-                    let pointer = r#return.return_pointer().expect("Always exists");
-                    let pointer = unsafe {
-                        context.builder().build_gep(
-                            pointer,
-                            &[
-                                context.field_const(0),
-                                context
-                                    .integer_type(compiler_common::BITLENGTH_X32)
-                                    .const_int(index as u64, false),
-                            ],
-                            format!("return_{}_gep_pointer", index).as_str(),
-                        )
-                    };
-                    let pointer = context.builder().build_pointer_cast(
-                        pointer,
-                        context
-                            .field_type()
-                            .ptr_type(compiler_llvm_context::AddressSpace::Stack.into()),
-                        format!("return_{}_gep_pointer_field", index).as_str(),
-                    );
-                    context
-                        .function_mut()
-                        .stack
-                        .insert(identifier.name.clone(), pointer);
-                }
-                r#return
-            }
-            None => {
-                if let Some(identifier) = self.result.pop() {
-                    let r#type = identifier.yul_type.unwrap_or_default();
-                    let pointer =
-                        context.build_alloca(r#type.clone().into_llvm(context), "return_pointer");
-                    context.build_store(pointer, r#type.into_llvm(context).const_zero());
-                    context
-                        .function_mut()
-                        .stack
-                        .insert(identifier.name, pointer);
-                    compiler_llvm_context::FunctionReturn::primitive(pointer)
-                } else {
-                    compiler_llvm_context::FunctionReturn::none()
-                }
-            }
+            Some(r#return) => r#return,
+            None => match results.first() {
+                Some((_, pointer)) => compiler_llvm_context::FunctionReturn::primitive(*pointer),
+                None => compiler_llvm_context::FunctionReturn::none(),
+            },
         };
 
         let argument_types: Vec<_> = self
@@ -225,7 +239,9 @@ where
                 context.build_catch_block(false);
 
                 context.set_basic_block(context.function().return_block);
-                let return_value = context.build_load(pointer, "return_value");
+                let return_value = context.build_load(pointer, "return_value").into_int_value();
+                let (r#type, _) = results.remove(0);
+                let return_value = r#type.convert_llvm(context, return_value, context.field_type());
                 context.build_return(Some(&return_value));
             }
             compiler_llvm_context::FunctionReturn::Compound {
@@ -236,6 +252,25 @@ where
                 context.build_catch_block(false);
 
                 context.set_basic_block(context.function().return_block);
+                for (index, (r#type, pointer)) in results.into_iter().enumerate() {
+                    let value = context
+                        .build_load(pointer, format!("return_{}_value", index).as_str())
+                        .into_int_value();
+                    let value = r#type.convert_llvm(context, value, context.field_type());
+                    let pointer = unsafe {
+                        context.builder().build_gep(
+                            return_pointer,
+                            &[
+                                context.field_const(0),
+                                context
+                                    .integer_type(compiler_common::BITLENGTH_X32)
+                                    .const_int(index as u64, false),
+                            ],
+                            format!("return_{}_gep_pointer", index).as_str(),
+                        )
+                    };
+                    context.build_store(pointer, value);
+                }
                 context.build_return(Some(&return_pointer));
             }
         }
@@ -243,3 +278,18 @@ where
         Ok(())
     }
 }
+
+impl fmt::Display for FunctionDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "function {}({})",
+            self.name,
+            Identifier::join(self.arguments.as_slice())
+        )?;
+        if !self.result.is_empty() {
+            write!(f, " -> {}", Identifier::join(self.result.as_slice()))?;
+        }
+        write!(f, " {}", self.body)
+    }
+}
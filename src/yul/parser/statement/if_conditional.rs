@@ -2,8 +2,14 @@
 //! The if-conditional statement.
 //!
 
+use std::fmt;
+
+use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::Expression;
 
@@ -12,6 +18,8 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct IfConditional {
+    /// The location.
+    pub location: Location,
     /// The condition expression.
     pub condition: Expression,
     /// The conditional block.
@@ -22,14 +30,27 @@ impl IfConditional {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::If),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["if"])),
+        };
 
-        let condition = Expression::parse(lexer, Some(lexeme))?;
+        let condition = Expression::parse(lexer, None)?;
 
         let block = Block::parse(lexer, None)?;
 
-        Ok(Self { condition, block })
+        Ok(Self {
+            location,
+            condition,
+            block,
+        })
     }
 }
 
@@ -66,3 +87,9 @@ where
         Ok(())
     }
 }
+
+impl fmt::Display for IfConditional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "if {} {}", self.condition, self.block)
+    }
+}
@@ -13,9 +13,14 @@ pub mod object;
 pub mod switch;
 pub mod variable_declaration;
 
+use std::fmt;
+
 use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 
 use self::assignment::Assignment;
 use self::block::Block;
@@ -54,11 +59,11 @@ pub enum Statement {
     /// The `for` statement.
     ForLoop(ForLoop),
     /// The `continue` statement.
-    Continue,
+    Continue(Location),
     /// The `break` statement.
-    Break,
+    Break(Location),
     /// The `leave` statement.
-    Leave,
+    Leave(Location),
 }
 
 impl Statement {
@@ -67,48 +72,114 @@ impl Statement {
     ///
     pub fn parse(
         lexer: &mut Lexer,
-        initial: Option<Lexeme>,
-    ) -> anyhow::Result<(Self, Option<Lexeme>)> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+        initial: Option<Token>,
+    ) -> anyhow::Result<(Self, Option<Token>)> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        match lexeme {
-            lexeme @ Lexeme::Keyword(Keyword::Object) => {
-                Ok((Statement::Object(Object::parse(lexer, Some(lexeme))?), None))
-            }
-            Lexeme::Keyword(Keyword::Code) => {
-                Ok((Statement::Code(Code::parse(lexer, None)?), None))
-            }
-            Lexeme::Keyword(Keyword::Function) => Ok((
-                Statement::FunctionDefinition(FunctionDefinition::parse(lexer, None)?),
+        match token {
+            token @ Token {
+                lexeme: Lexeme::Keyword(Keyword::Object),
+                ..
+            } => Ok((Statement::Object(Object::parse(lexer, Some(token))?), None)),
+            token @ Token {
+                lexeme: Lexeme::Keyword(Keyword::Code),
+                ..
+            } => Ok((Statement::Code(Code::parse(lexer, Some(token))?), None)),
+            token @ Token {
+                lexeme: Lexeme::Keyword(Keyword::Function),
+                ..
+            } => Ok((
+                Statement::FunctionDefinition(FunctionDefinition::parse(lexer, Some(token))?),
                 None,
             )),
-            Lexeme::Keyword(Keyword::Let) => {
-                let (statement, next) = VariableDeclaration::parse(lexer, None)?;
+            token @ Token {
+                lexeme: Lexeme::Keyword(Keyword::Let),
+                ..
+            } => {
+                let (statement, next) = VariableDeclaration::parse(lexer, Some(token))?;
                 Ok((Statement::VariableDeclaration(statement), next))
             }
-            Lexeme::Keyword(Keyword::If) => Ok((
-                Statement::IfConditional(IfConditional::parse(lexer, None)?),
+            token @ Token {
+                lexeme: Lexeme::Keyword(Keyword::If),
+                ..
+            } => Ok((
+                Statement::IfConditional(IfConditional::parse(lexer, Some(token))?),
                 None,
             )),
-            Lexeme::Keyword(Keyword::Switch) => {
-                Ok((Statement::Switch(Switch::parse(lexer, None)?), None))
-            }
-            Lexeme::Keyword(Keyword::For) => {
-                Ok((Statement::ForLoop(ForLoop::parse(lexer, None)?), None))
-            }
-            Lexeme::Keyword(Keyword::Continue) => Ok((Statement::Continue, None)),
-            Lexeme::Keyword(Keyword::Break) => Ok((Statement::Break, None)),
-            Lexeme::Keyword(Keyword::Leave) => Ok((Statement::Leave, None)),
-            lexeme => {
-                anyhow::bail!(
-                    "Expected one of {:?}, found `{}`",
-                    [
-                        "object", "code", "function", "let", "if", "switch", "for", "continue",
-                        "break", "leave",
-                    ],
-                    lexeme
-                );
-            }
+            token @ Token {
+                lexeme: Lexeme::Keyword(Keyword::Switch),
+                ..
+            } => Ok((Statement::Switch(Switch::parse(lexer, Some(token))?), None)),
+            token @ Token {
+                lexeme: Lexeme::Keyword(Keyword::For),
+                ..
+            } => Ok((
+                Statement::ForLoop(ForLoop::parse(lexer, Some(token))?),
+                None,
+            )),
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Continue),
+                location,
+                ..
+            } => Ok((Statement::Continue(location), None)),
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Break),
+                location,
+                ..
+            } => Ok((Statement::Break(location), None)),
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Leave),
+                location,
+                ..
+            } => Ok((Statement::Leave(location), None)),
+            token => anyhow::bail!(ParserError::invalid_token(
+                token,
+                vec![
+                    "object", "code", "function", "let", "if", "switch", "for", "continue",
+                    "break", "leave",
+                ],
+            )),
+        }
+    }
+
+    ///
+    /// Returns the statement location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::Object(inner) => inner.location.clone(),
+            Self::Code(inner) => inner.location.clone(),
+            Self::Block(inner) => inner.location.clone(),
+            Self::Expression(inner) => inner.location(),
+            Self::FunctionDefinition(inner) => inner.location.clone(),
+            Self::VariableDeclaration(inner) => inner.location.clone(),
+            Self::Assignment(inner) => inner.location.clone(),
+            Self::IfConditional(inner) => inner.location.clone(),
+            Self::Switch(inner) => inner.location.clone(),
+            Self::ForLoop(inner) => inner.location.clone(),
+            Self::Continue(location) => location.clone(),
+            Self::Break(location) => location.clone(),
+            Self::Leave(location) => location.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(inner) => write!(f, "{}", inner),
+            Self::Code(inner) => write!(f, "{}", inner),
+            Self::Block(inner) => write!(f, "{}", inner),
+            Self::Expression(inner) => write!(f, "{}", inner),
+            Self::FunctionDefinition(inner) => write!(f, "{}", inner),
+            Self::VariableDeclaration(inner) => write!(f, "{}", inner),
+            Self::Assignment(inner) => write!(f, "{}", inner),
+            Self::IfConditional(inner) => write!(f, "{}", inner),
+            Self::Switch(inner) => write!(f, "{}", inner),
+            Self::ForLoop(inner) => write!(f, "{}", inner),
+            Self::Continue(_) => write!(f, "continue"),
+            Self::Break(_) => write!(f, "break"),
+            Self::Leave(_) => write!(f, "leave"),
         }
     }
 }
@@ -0,0 +1,79 @@
+//!
+//! The YUL object data section.
+//!
+
+use std::fmt;
+
+use crate::yul::lexer::lexeme::literal::string::String as StringLiteral;
+use crate::yul::lexer::lexeme::literal::Literal;
+use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
+use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
+
+///
+/// The YUL object data section.
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct Data {
+    /// The location.
+    pub location: Location,
+    /// The identifier.
+    pub identifier: String,
+    /// The contents, either a string or a hexadecimal string.
+    pub value: StringLiteral,
+}
+
+impl Data {
+    ///
+    /// The element parser, which acts like a constructor.
+    ///
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Identifier(ref identifier),
+                location,
+                ..
+            } if identifier.as_str() == "data" => location,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["data"])),
+        };
+
+        let identifier = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                ..
+            } if !literal.is_hexadecimal => literal.inner,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["{string}"])),
+        };
+
+        let value = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                ..
+            } => literal,
+            token => anyhow::bail!(ParserError::invalid_token(
+                token,
+                vec!["{string}", "{hex string}"]
+            )),
+        };
+
+        Ok(Self {
+            location,
+            identifier,
+            value,
+        })
+    }
+}
+
+impl fmt::Display for Data {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "data \"{}\" ", self.identifier)?;
+        if self.value.is_hexadecimal {
+            write!(f, "hex")?;
+        }
+        write!(f, "\"{}\"", self.value.inner)
+    }
+}
@@ -0,0 +1,109 @@
+//!
+//! The YUL object layout.
+//!
+
+use crate::yul::parser::statement::object::Object;
+
+///
+/// The YUL object layout.
+///
+/// Chooses the deploy and runtime code objects of the contracts among the top-level objects of
+/// a file. By default, each top-level object is the deploy code of a contract, and its inner
+/// object with the `_deployed` suffix, following the solc naming, is the runtime code.
+///
+/// The dependency objects are always laid out by the solc naming.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Layout {
+    /// The deploy code object identifier, which selects the only contract of a file.
+    pub deploy: Option<String>,
+    /// The runtime code object identifier, which is looked up among the inner objects of the
+    /// deploy code object, and then among the other top-level objects.
+    pub runtime: Option<String>,
+}
+
+impl Layout {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(deploy: Option<String>, runtime: Option<String>) -> Self {
+        Self { deploy, runtime }
+    }
+
+    ///
+    /// Selects the contract objects among the top-level `objects` of a file, and sets their
+    /// runtime code objects.
+    ///
+    /// A top-level runtime code object is not a contract of its own.
+    ///
+    pub fn apply(&self, mut objects: Vec<Object>) -> anyhow::Result<Vec<Object>> {
+        let top_level_runtime = self.runtime.as_ref().and_then(|runtime| {
+            objects
+                .iter()
+                .position(|object| object.identifier.as_str() == runtime.as_str())
+                .map(|index| objects.remove(index))
+        });
+
+        let mut contracts = match self.deploy {
+            Some(ref deploy) => {
+                let index = objects
+                    .iter()
+                    .position(|object| object.identifier.as_str() == deploy.as_str())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "The deploy code object `{}` is not found among the top-level objects: {}",
+                            deploy,
+                            Self::identifiers(objects.as_slice())
+                        )
+                    })?;
+                vec![objects.remove(index)]
+            }
+            None => objects,
+        };
+
+        for contract in contracts.iter_mut() {
+            if let Some(ref runtime) = self.runtime {
+                if contract
+                    .object
+                    .as_ref()
+                    .map(|object| object.identifier.as_str() == runtime.as_str())
+                    .unwrap_or_default()
+                {
+                    continue;
+                }
+
+                let object = match contract.take_dependency(runtime.as_str()) {
+                    Some(object) => object,
+                    None => top_level_runtime.to_owned().ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "The runtime code object `{}` of `{}` is not found",
+                            runtime,
+                            contract.identifier
+                        )
+                    })?,
+                };
+                contract.set_runtime(object);
+            } else if contract.object.is_none() {
+                anyhow::bail!(
+                    "The runtime code object `{}{}` of `{}` is not found",
+                    contract.identifier,
+                    Object::RUNTIME_SUFFIX,
+                    contract.identifier
+                );
+            }
+        }
+
+        Ok(contracts)
+    }
+
+    ///
+    /// Joins the `objects` identifiers for the error messages.
+    ///
+    fn identifiers(objects: &[Object]) -> String {
+        objects
+            .iter()
+            .map(|object| format!("`{}`", object.identifier))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
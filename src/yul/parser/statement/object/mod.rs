@@ -0,0 +1,411 @@
+//!
+//! The YUL object.
+//!
+
+pub mod data;
+pub mod layout;
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::yul::lexer::lexeme::keyword::Keyword;
+use crate::yul::lexer::lexeme::literal::Literal;
+use crate::yul::lexer::lexeme::symbol::Symbol;
+use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
+use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
+use crate::yul::parser::statement::code::Code;
+
+use self::data::Data;
+
+///
+/// The YUL object.
+///
+/// The `Display` implementation prints the canonical YUL source, which is parsed back into
+/// an object equal to this one except for the locations, as checked by `LocationEraser::eq`.
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct Object {
+    /// The location.
+    pub location: Location,
+    /// The identifier.
+    pub identifier: String,
+    /// The code.
+    pub code: Code,
+    /// The runtime code object, if the object is the deploy code.
+    pub object: Option<Box<Self>>,
+    /// The other inner objects, usually related to factory dependencies.
+    pub dependencies: Vec<Self>,
+    /// The data sections.
+    pub data: Vec<Data>,
+    /// The Solidity source file paths by their indexes, specified by the `@use-src` annotation.
+    pub sources: BTreeMap<usize, String>,
+}
+
+impl Object {
+    /// The solc runtime code object identifier suffix.
+    pub const RUNTIME_SUFFIX: &'static str = "_deployed";
+
+    ///
+    /// Parses the object in the error recovery mode.
+    ///
+    /// Returns the partial object, if its header has been parsed, and all syntax errors found.
+    /// The locations refer to the source file `path`, if specified.
+    ///
+    pub fn parse_recovering(
+        input: String,
+        path: Option<&str>,
+    ) -> (Option<Self>, Vec<anyhow::Error>) {
+        let mut lexer = Lexer::new_recovering(input, path);
+        let object = Self::parse(&mut lexer, None);
+
+        let mut errors = lexer.take_errors();
+        let object = match object {
+            Ok(object) => Some(object),
+            Err(error) => {
+                errors.push(error);
+                None
+            }
+        };
+
+        (object, errors)
+    }
+
+    ///
+    /// Parses all top-level objects of a file in the error recovery mode.
+    ///
+    /// Returns the objects parsed before the first unrecoverable error, and all syntax errors found.
+    /// The locations refer to the source file `path`, if specified.
+    ///
+    pub fn parse_all_recovering(
+        input: String,
+        path: Option<&str>,
+    ) -> (Vec<Self>, Vec<anyhow::Error>) {
+        let mut lexer = Lexer::new_recovering(input, path);
+        let mut objects = Vec::new();
+        let mut error = None;
+        loop {
+            match lexer.peek() {
+                Ok(Token {
+                    lexeme: Lexeme::EndOfFile,
+                    ..
+                }) => break,
+                Ok(_) => {}
+                Err(inner) => {
+                    error = Some(inner);
+                    break;
+                }
+            }
+
+            match Self::parse(&mut lexer, None) {
+                Ok(object) => objects.push(object),
+                Err(inner) => {
+                    error = Some(inner);
+                    break;
+                }
+            }
+        }
+
+        let mut errors = lexer.take_errors();
+        errors.extend(error);
+        (objects, errors)
+    }
+
+    ///
+    /// The element parser, which acts like a constructor.
+    ///
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Object),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["object"])),
+        };
+        let sources = lexer.take_sources();
+
+        let identifier = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                ..
+            } => literal.inner,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["{string}"])),
+        };
+
+        match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                ..
+            } => {}
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["{"])),
+        }
+
+        let code = Code::parse(lexer, None)?;
+
+        let mut objects = Vec::new();
+        let mut data = Vec::new();
+        loop {
+            match lexer.next()? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    ..
+                } => break,
+                token @ Token {
+                    lexeme: Lexeme::Keyword(Keyword::Object),
+                    ..
+                } => {
+                    objects.push(Self::parse(lexer, Some(token))?);
+                }
+                token if token.lexeme == Lexeme::Identifier("data".to_owned()) => {
+                    data.push(Data::parse(lexer, Some(token))?);
+                }
+                token => {
+                    lexer.recover(
+                        ParserError::invalid_token(token, vec!["object", "data", "}"]).into(),
+                    )?;
+                    break;
+                }
+            }
+        }
+
+        let runtime_identifier = format!("{}{}", identifier, Self::RUNTIME_SUFFIX);
+        let object = objects
+            .iter()
+            .position(|object| object.identifier == runtime_identifier)
+            .map(|index| Box::new(objects.remove(index)));
+
+        Ok(Self {
+            location,
+            identifier,
+            code,
+            object,
+            dependencies: objects,
+            data,
+            sources,
+        })
+    }
+
+    ///
+    /// Makes the `runtime` object the runtime code, moving the previous one to the dependencies.
+    ///
+    pub fn set_runtime(&mut self, runtime: Self) {
+        if let Some(previous) = self.object.replace(Box::new(runtime)) {
+            self.dependencies.push(*previous);
+        }
+    }
+
+    ///
+    /// Removes the inner object `identifier` from the dependencies and returns it.
+    ///
+    pub fn take_dependency(&mut self, identifier: &str) -> Option<Self> {
+        let index = self
+            .dependencies
+            .iter()
+            .position(|dependency| dependency.identifier == identifier)?;
+        Some(self.dependencies.remove(index))
+    }
+
+    ///
+    /// Returns the copies of the dependency objects in the whole tree, including those of the
+    /// runtime code object.
+    ///
+    /// Each dependency is a deploy code object compiled as a separate contract.
+    ///
+    pub fn nested_dependencies(&self) -> Vec<Self> {
+        let mut nested = Vec::new();
+        for dependency in self.dependencies.iter().chain(
+            self.object
+                .iter()
+                .flat_map(|object| object.dependencies.iter()),
+        ) {
+            nested.push(dependency.to_owned());
+            nested.extend(dependency.nested_dependencies());
+        }
+        nested
+    }
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.sources.is_empty() {
+            let sources = self
+                .sources
+                .iter()
+                .map(|(index, path)| format!("{}:\"{}\"", index, path))
+                .collect::<Vec<String>>()
+                .join(", ");
+            writeln!(f, "/// @use-src {}", sources)?;
+        }
+        writeln!(f, "object \"{}\" {{", self.identifier)?;
+        crate::yul::parser::write_indented(f, &self.code)?;
+        if let Some(ref object) = self.object {
+            crate::yul::parser::write_indented(f, object)?;
+        }
+        for data in self.data.iter() {
+            crate::yul::parser::write_indented(f, data)?;
+        }
+        for dependency in self.dependencies.iter() {
+            crate::yul::parser::write_indented(f, dependency)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl<D> compiler_llvm_context::WriteLLVM<D> for Object
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        let mut entry = compiler_llvm_context::EntryFunction::default();
+        entry.declare(context)?;
+
+        compiler_llvm_context::ConstructorFunction::new(
+            compiler_llvm_context::DummyLLVMWritable::default(),
+        )
+        .declare(context)?;
+        compiler_llvm_context::SelectorFunction::new(
+            compiler_llvm_context::DummyLLVMWritable::default(),
+        )
+        .declare(context)?;
+
+        entry.into_llvm(context)?;
+
+        Ok(())
+    }
+
+    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        let runtime = self.object.ok_or_else(|| {
+            anyhow::anyhow!(
+                "The runtime code object of `{}` is not found",
+                self.identifier
+            )
+        })?;
+
+        context.code_type = Some(compiler_llvm_context::CodeType::Deploy);
+        compiler_llvm_context::ConstructorFunction::new(self.code).into_llvm(context)?;
+        context.code_type = Some(compiler_llvm_context::CodeType::Runtime);
+        compiler_llvm_context::SelectorFunction::new(runtime.code).into_llvm(context)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::Lexer;
+    use crate::yul::location_eraser::LocationEraser;
+
+    use super::Object;
+
+    ///
+    /// Checks that the printed `input` object is parsed back into an equal object, and that the
+    /// printed source is canonical, i.e. it is printed back unchanged.
+    ///
+    fn check_round_trip(input: &str) {
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Invalid test input");
+
+        let printed = object.to_string();
+        let mut lexer = Lexer::new(printed.clone());
+        let reparsed = Object::parse(&mut lexer, None)
+            .unwrap_or_else(|error| panic!("Printed object parsing error: {}\n{}", error, printed));
+
+        assert!(
+            LocationEraser::eq(&object, &reparsed),
+            "The printed object is parsed into a different one:\n{}",
+            printed
+        );
+        assert_eq!(printed, reparsed.to_string());
+    }
+
+    #[test]
+    fn object() {
+        check_round_trip(
+            r#"
+object "Test" {
+    code {
+        function add(a, b) -> result { result := add(a, b) }
+        let x, y := add(1, 0x2)
+        if lt(x, y) { x := 3 }
+        switch x case 0 { y := 1 } case "a" { leave } default { y := 2 }
+        for { let i := 0 } lt(i, 10) { i := add(i, 1) } { if eq(i, 5) { break } continue }
+        mstore(0, true)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn nested_objects() {
+        check_round_trip(
+            r#"
+object "Test" {
+    code { return(0, 0) }
+    object "Test_deployed" {
+        code { revert(0, 0) }
+        object "Dependency" {
+            code { stop() }
+            object "Dependency_deployed" { code { invalid() } }
+        }
+    }
+    object "Other" { code { } }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn data_sections() {
+        check_round_trip(
+            r#"
+object "Test" {
+    code { datacopy(0, dataoffset("Hex"), datasize("Hex")) }
+    data "Hex" hex"0102ABcdef"
+    data "String" "text"
+    object "Test_deployed" {
+        code { }
+        data ".metadata" hex"a2646970667358"
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn typed_identifiers() {
+        check_round_trip(
+            r#"
+object "Test" {
+    code {
+        function convert(a:uint64, b:bool) -> r:uint32, s:int8 { r := 1:uint32 }
+        let x:uint64 := 0x20:uint64
+        let y:bool := true:bool
+        let z:int8, w:uint32 := convert(x, y)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn escaped_string_literals() {
+        check_round_trip(
+            r#"
+object "Test" {
+    code {
+        let a := "quote \" and backslash \\"
+        let b := "\n\r\t\'\x41\u00e9é"
+        let c := hex"00ff"
+    }
+    data "Escaped" "line\nbreak\x00"
+}
+"#,
+        );
+    }
+}
@@ -4,9 +4,14 @@
 
 pub mod case;
 
+use std::fmt;
+
 use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::Expression;
 
@@ -17,6 +22,8 @@ use self::case::Case;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Switch {
+    /// The location.
+    pub location: Location,
     /// The expression being matched.
     pub expression: Expression,
     /// The non-default cases.
@@ -41,23 +48,45 @@ impl Switch {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
         let mut state = State::CaseOrDefaultKeyword;
 
-        let expression = Expression::parse(lexer, Some(lexeme.clone()))?;
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Switch),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["switch"])),
+        };
+
+        let expression = Expression::parse(lexer, None)?;
         let mut cases = Vec::new();
         let mut default = None;
 
         loop {
             match state {
                 State::CaseOrDefaultKeyword => match lexer.peek()? {
-                    Lexeme::Keyword(Keyword::Case) => state = State::CaseBlock,
-                    Lexeme::Keyword(Keyword::Default) => state = State::DefaultBlock,
-                    _ => break,
+                    Token {
+                        lexeme: Lexeme::Keyword(Keyword::Case),
+                        ..
+                    } => state = State::CaseBlock,
+                    Token {
+                        lexeme: Lexeme::Keyword(Keyword::Default),
+                        ..
+                    } => state = State::DefaultBlock,
+                    token => {
+                        if cases.is_empty() && default.is_none() {
+                            anyhow::bail!(ParserError::invalid_token(
+                                token,
+                                vec!["case", "default"],
+                            ));
+                        }
+                        break;
+                    }
                 },
                 State::CaseBlock => {
-                    lexer.next()?;
                     cases.push(Case::parse(lexer, None)?);
                     state = State::CaseOrDefaultKeyword;
                 }
@@ -69,15 +98,8 @@ impl Switch {
             }
         }
 
-        if cases.is_empty() && default.is_none() {
-            anyhow::bail!(
-                "Expected one of {:?}, found `{}`",
-                ["case", "default"],
-                lexeme
-            );
-        }
-
         Ok(Self {
+            location,
             expression,
             cases,
             default,
@@ -115,11 +137,14 @@ where
                 .clone()
                 .into_llvm(context)?
                 .expect("Always exists");
-            let constant = case.literal.into_llvm(context);
+            let scrutinee = scrutinee.to_llvm().into_int_value();
+            let constant_type = case.literal.yul_type.to_owned().unwrap_or_default();
+            let constant = case.literal.into_llvm(context).to_llvm().into_int_value();
+            let constant = constant_type.convert_llvm(context, constant, scrutinee.get_type());
             let comparison = context.builder().build_int_compare(
                 inkwell::IntPredicate::EQ,
-                constant.to_llvm().into_int_value(),
-                scrutinee.to_llvm().into_int_value(),
+                constant,
+                scrutinee,
                 format!("switch_case_condition_{}", index).as_str(),
             );
 
@@ -140,3 +165,16 @@ where
         Ok(())
     }
 }
+
+impl fmt::Display for Switch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "switch {}", self.expression)?;
+        for case in self.cases.iter() {
+            write!(f, "\n{}", case)?;
+        }
+        if let Some(ref default) = self.default {
+            write!(f, "\ndefault {}", default)?;
+        }
+        Ok(())
+    }
+}
@@ -2,13 +2,20 @@
 //! The variable declaration statement.
 //!
 
+use std::fmt;
+
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
 
+use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::r#type::Type;
 use crate::yul::parser::statement::expression::Expression;
 
 ///
@@ -16,6 +23,8 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct VariableDeclaration {
+    /// The location.
+    pub location: Location,
     /// The variable bindings list.
     pub bindings: Vec<Identifier>,
     /// The variable initializing expression.
@@ -28,21 +37,34 @@ impl VariableDeclaration {
     ///
     pub fn parse(
         lexer: &mut Lexer,
-        initial: Option<Lexeme>,
-    ) -> anyhow::Result<(Self, Option<Lexeme>)> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+        initial: Option<Token>,
+    ) -> anyhow::Result<(Self, Option<Token>)> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Let),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(ParserError::invalid_token(token, vec!["let"])),
+        };
 
-        let (bindings, next) = Identifier::parse_typed_list(lexer, Some(lexeme))?;
+        let (bindings, next) = Identifier::parse_typed_list(lexer, None)?;
 
         match crate::yul::parser::take_or_next(next, lexer)? {
-            Lexeme::Symbol(Symbol::Assignment) => {}
-            lexeme => {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Assignment),
+                ..
+            } => {}
+            token => {
                 return Ok((
                     Self {
+                        location,
                         bindings,
                         expression: None,
                     },
-                    Some(lexeme),
+                    Some(token),
                 ))
             }
         }
@@ -51,6 +73,7 @@ impl VariableDeclaration {
 
         Ok((
             Self {
+                location,
                 bindings,
                 expression: Some(expression),
             },
@@ -73,8 +96,11 @@ where
                 .stack
                 .insert(identifier.name, pointer);
             let value = if let Some(expression) = self.expression {
+                let expression_type = expression.yul_type();
                 match expression.into_llvm(context)? {
-                    Some(value) => value.to_llvm(),
+                    Some(value) => expression_type
+                        .convert_llvm(context, value.to_llvm().into_int_value(), r#type)
+                        .as_basic_value_enum(),
                     None => r#type.const_zero().as_basic_value_enum(),
                 }
             } else {
@@ -84,72 +110,79 @@ where
             return Ok(());
         }
 
-        let llvm_type = context.structure_type(
-            self.bindings
-                .iter()
-                .map(|binding| {
-                    binding
-                        .yul_type
-                        .to_owned()
-                        .unwrap_or_default()
-                        .into_llvm(context)
-                        .as_basic_type_enum()
-                })
-                .collect(),
-        );
-        let pointer = context.build_alloca(llvm_type, "bindings_pointer");
+        let mut types = Vec::with_capacity(self.bindings.len());
         for (index, binding) in self.bindings.iter().enumerate() {
-            let yul_type = binding
-                .yul_type
-                .to_owned()
-                .unwrap_or_default()
-                .into_llvm(context);
+            let yul_type = binding.yul_type.to_owned().unwrap_or_default();
             let pointer = context.build_alloca(
-                yul_type.as_basic_type_enum(),
+                yul_type.clone().into_llvm(context).as_basic_type_enum(),
                 format!("binding_{}_pointer", index).as_str(),
             );
             context
                 .function_mut()
                 .stack
                 .insert(binding.name.to_owned(), pointer);
+            types.push(yul_type);
         }
 
-        match self.expression.take() {
-            Some(expression) => {
-                if let Some(value) = expression.into_llvm(context)? {
-                    context.build_store(pointer, value.to_llvm());
-
-                    for (index, binding) in self.bindings.into_iter().enumerate() {
-                        let pointer = unsafe {
-                            context.builder().build_gep(
-                                pointer,
-                                &[
-                                    context.field_const(0),
-                                    context
-                                        .integer_type(compiler_common::BITLENGTH_X32)
-                                        .const_int(index as u64, false),
-                                ],
-                                format!("binding_{}_gep_pointer", index).as_str(),
-                            )
-                        };
-
-                        let value = context
-                            .build_load(pointer, format!("binding_{}_value", index).as_str());
-                        let pointer = context
-                            .function_mut()
-                            .stack
-                            .get(binding.name.as_str())
-                            .cloned()
-                            .expect("Always exists");
-                        context.build_store(pointer, value);
-                    }
-                }
-            }
+        let value = match self.expression.take() {
+            Some(expression) => expression.into_llvm(context)?,
+            None => None,
+        };
+        let value = match value {
+            Some(value) => value,
             None => {
-                context.build_store(pointer, llvm_type.const_zero());
+                for (binding, yul_type) in self.bindings.into_iter().zip(types) {
+                    let pointer = context.function().stack[binding.name.as_str()];
+                    context.build_store(pointer, yul_type.into_llvm(context).const_zero());
+                }
+                return Ok(());
             }
+        };
+
+        let llvm_type = context.structure_type(vec![
+            context.field_type().as_basic_type_enum();
+            self.bindings.len()
+        ]);
+        let pointer = context.build_alloca(llvm_type, "bindings_pointer");
+        context.build_store(pointer, value.to_llvm());
+
+        for (index, (binding, yul_type)) in self.bindings.into_iter().zip(types).enumerate() {
+            let pointer = unsafe {
+                context.builder().build_gep(
+                    pointer,
+                    &[
+                        context.field_const(0),
+                        context
+                            .integer_type(compiler_common::BITLENGTH_X32)
+                            .const_int(index as u64, false),
+                    ],
+                    format!("binding_{}_gep_pointer", index).as_str(),
+                )
+            };
+
+            let value = context
+                .build_load(pointer, format!("binding_{}_value", index).as_str())
+                .into_int_value();
+            let value = Type::default().convert_llvm(context, value, yul_type.into_llvm(context));
+            let pointer = context
+                .function_mut()
+                .stack
+                .get(binding.name.as_str())
+                .cloned()
+                .expect("Always exists");
+            context.build_store(pointer, value);
         }
 
         Ok(())
     }
 }
+
+impl fmt::Display for VariableDeclaration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "let {}", Identifier::join(self.bindings.as_slice()))?;
+        if let Some(ref expression) = self.expression {
+            write!(f, " := {}", expression)?;
+        }
+        Ok(())
+    }
+}
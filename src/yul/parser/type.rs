@@ -2,9 +2,13 @@
 //! The YUL source code type.
 //!
 
+use std::fmt;
+
 use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 
 ///
 /// The YUL source code type.
@@ -31,15 +35,93 @@ impl Type {
     ///
     /// The element parser, which acts like a constructor.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        match lexeme {
+        match token.lexeme {
             Lexeme::Keyword(Keyword::Bool) => Ok(Self::Bool),
             Lexeme::Keyword(Keyword::Int(bitlength)) => Ok(Self::Int(bitlength)),
             Lexeme::Keyword(Keyword::Uint(bitlength)) => Ok(Self::UInt(bitlength)),
             Lexeme::Identifier(identifier) => Ok(Self::Custom(identifier)),
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["{type}"], lexeme),
+            _ => anyhow::bail!(ParserError::invalid_token(token, vec!["{type}"])),
+        }
+    }
+
+    ///
+    /// Checks whether the type is supported by the compiler.
+    ///
+    /// The integer types must be at most 256 bits wide, and their width must be a multiple of
+    /// 8 bits. The custom types are not supported.
+    ///
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::Bool => true,
+            Self::Int(bitlength) | Self::UInt(bitlength) => {
+                *bitlength > 0
+                    && *bitlength <= compiler_common::BITLENGTH_FIELD
+                    && *bitlength % compiler_common::BITLENGTH_BYTE == 0
+            }
+            Self::Custom(_) => false,
+        }
+    }
+
+    ///
+    /// Checks whether the type is a signed integer.
+    ///
+    pub fn is_signed(&self) -> bool {
+        matches!(self, Self::Int(_))
+    }
+
+    ///
+    /// Checks whether a value of the type can be used where the `other` type is expected.
+    ///
+    /// Only the lossless widening conversions are implicit: an integer to a wider integer of the
+    /// same signedness, and an unsigned integer to a strictly wider signed one. The other
+    /// conversions must be done explicitly with the `to_{type}` builtins.
+    ///
+    pub fn is_implicitly_convertible_to(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::UInt(from), Self::UInt(to)) => from <= to,
+            (Self::Int(from), Self::Int(to)) => from <= to,
+            (Self::UInt(from), Self::Int(to)) => from < to,
+            (from, to) => from == to,
+        }
+    }
+
+    ///
+    /// Converts the `value` of the type to the `target` LLVM type.
+    ///
+    /// The narrower values are extended according to the type signedness, and the wider ones
+    /// are truncated.
+    ///
+    pub fn convert_llvm<'ctx, D>(
+        &self,
+        context: &compiler_llvm_context::Context<'ctx, D>,
+        value: inkwell::values::IntValue<'ctx>,
+        target: inkwell::types::IntType<'ctx>,
+    ) -> inkwell::values::IntValue<'ctx>
+    where
+        D: compiler_llvm_context::Dependency,
+    {
+        let source_bitlength = value.get_type().get_bit_width();
+        let target_bitlength = target.get_bit_width();
+
+        if source_bitlength < target_bitlength {
+            if self.is_signed() {
+                context
+                    .builder()
+                    .build_int_s_extend_or_bit_cast(value, target, "sign_extended")
+            } else {
+                context
+                    .builder()
+                    .build_int_z_extend_or_bit_cast(value, target, "zero_extended")
+            }
+        } else if source_bitlength > target_bitlength {
+            context
+                .builder()
+                .build_int_truncate_or_bit_cast(value, target, "truncated")
+        } else {
+            value
         }
     }
 
@@ -61,3 +143,14 @@ impl Type {
         }
     }
 }
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::Int(bitlength) => write!(f, "int{}", bitlength),
+            Self::UInt(bitlength) => write!(f, "uint{}", bitlength),
+            Self::Custom(inner) => write!(f, "{}", inner),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//!
+//! The YUL syntax tree visitor.
+//!
+
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::data::Data;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::case::Case;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+
+///
+/// The YUL syntax tree visitor.
+///
+/// Each `visit_*` method defaults to the corresponding `walk_*` method, which visits the node
+/// children in the source code order. An implementation overrides the methods of the nodes it
+/// is interested in, and calls the `walk_*` method to continue the traversal into the children.
+///
+pub trait Visitor {
+    ///
+    /// Visits an object.
+    ///
+    fn visit_object(&mut self, object: &Object) {
+        self.walk_object(object);
+    }
+
+    ///
+    /// Visits the object children: the code, the inner object, the data sections and the
+    /// dependencies.
+    ///
+    fn walk_object(&mut self, object: &Object) {
+        self.visit_code(&object.code);
+        if let Some(ref inner) = object.object {
+            self.visit_object(inner);
+        }
+        for data in object.data.iter() {
+            self.visit_data(data);
+        }
+        for dependency in object.dependencies.iter() {
+            self.visit_object(dependency);
+        }
+    }
+
+    ///
+    /// Visits an object data section.
+    ///
+    fn visit_data(&mut self, _data: &Data) {}
+
+    ///
+    /// Visits a code section.
+    ///
+    fn visit_code(&mut self, code: &Code) {
+        self.walk_code(code);
+    }
+
+    ///
+    /// Visits the code section block.
+    ///
+    fn walk_code(&mut self, code: &Code) {
+        self.visit_block(&code.block);
+    }
+
+    ///
+    /// Visits a block.
+    ///
+    fn visit_block(&mut self, block: &Block) {
+        self.walk_block(block);
+    }
+
+    ///
+    /// Visits the block statements.
+    ///
+    fn walk_block(&mut self, block: &Block) {
+        for statement in block.statements.iter() {
+            self.visit_statement(statement);
+        }
+    }
+
+    ///
+    /// Visits a statement.
+    ///
+    fn visit_statement(&mut self, statement: &Statement) {
+        self.walk_statement(statement);
+    }
+
+    ///
+    /// Dispatches the statement to the visitor method of its variant.
+    ///
+    fn walk_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Object(inner) => self.visit_object(inner),
+            Statement::Code(inner) => self.visit_code(inner),
+            Statement::Block(inner) => self.visit_block(inner),
+            Statement::Expression(inner) => self.visit_expression(inner),
+            Statement::FunctionDefinition(inner) => self.visit_function_definition(inner),
+            Statement::VariableDeclaration(inner) => self.visit_variable_declaration(inner),
+            Statement::Assignment(inner) => self.visit_assignment(inner),
+            Statement::IfConditional(inner) => self.visit_if_conditional(inner),
+            Statement::Switch(inner) => self.visit_switch(inner),
+            Statement::ForLoop(inner) => self.visit_for_loop(inner),
+            Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+        }
+    }
+
+    ///
+    /// Visits a function definition.
+    ///
+    fn visit_function_definition(&mut self, definition: &FunctionDefinition) {
+        self.walk_function_definition(definition);
+    }
+
+    ///
+    /// Visits the function arguments, return variables and body.
+    ///
+    fn walk_function_definition(&mut self, definition: &FunctionDefinition) {
+        for identifier in definition.arguments.iter() {
+            self.visit_identifier(identifier);
+        }
+        for identifier in definition.result.iter() {
+            self.visit_identifier(identifier);
+        }
+        self.visit_block(&definition.body);
+    }
+
+    ///
+    /// Visits a variable declaration.
+    ///
+    fn visit_variable_declaration(&mut self, declaration: &VariableDeclaration) {
+        self.walk_variable_declaration(declaration);
+    }
+
+    ///
+    /// Visits the declared variables and the initializing expression.
+    ///
+    fn walk_variable_declaration(&mut self, declaration: &VariableDeclaration) {
+        for identifier in declaration.bindings.iter() {
+            self.visit_identifier(identifier);
+        }
+        if let Some(ref expression) = declaration.expression {
+            self.visit_expression(expression);
+        }
+    }
+
+    ///
+    /// Visits an assignment.
+    ///
+    fn visit_assignment(&mut self, assignment: &Assignment) {
+        self.walk_assignment(assignment);
+    }
+
+    ///
+    /// Visits the assigned variables and the initializing expression.
+    ///
+    fn walk_assignment(&mut self, assignment: &Assignment) {
+        for identifier in assignment.bindings.iter() {
+            self.visit_identifier(identifier);
+        }
+        self.visit_expression(&assignment.initializer);
+    }
+
+    ///
+    /// Visits an `if` statement.
+    ///
+    fn visit_if_conditional(&mut self, conditional: &IfConditional) {
+        self.walk_if_conditional(conditional);
+    }
+
+    ///
+    /// Visits the condition and the conditional block.
+    ///
+    fn walk_if_conditional(&mut self, conditional: &IfConditional) {
+        self.visit_expression(&conditional.condition);
+        self.visit_block(&conditional.block);
+    }
+
+    ///
+    /// Visits a `switch` statement.
+    ///
+    fn visit_switch(&mut self, switch: &Switch) {
+        self.walk_switch(switch);
+    }
+
+    ///
+    /// Visits the matched expression, the cases and the default block.
+    ///
+    fn walk_switch(&mut self, switch: &Switch) {
+        self.visit_expression(&switch.expression);
+        for case in switch.cases.iter() {
+            self.visit_case(case);
+        }
+        if let Some(ref default) = switch.default {
+            self.visit_block(default);
+        }
+    }
+
+    ///
+    /// Visits a `switch` case.
+    ///
+    fn visit_case(&mut self, case: &Case) {
+        self.walk_case(case);
+    }
+
+    ///
+    /// Visits the case literal and block.
+    ///
+    fn walk_case(&mut self, case: &Case) {
+        self.visit_literal(&case.literal);
+        self.visit_block(&case.block);
+    }
+
+    ///
+    /// Visits a `for` statement.
+    ///
+    fn visit_for_loop(&mut self, for_loop: &ForLoop) {
+        self.walk_for_loop(for_loop);
+    }
+
+    ///
+    /// Visits the initializer, the condition, the finalizer and the body.
+    ///
+    fn walk_for_loop(&mut self, for_loop: &ForLoop) {
+        self.visit_block(&for_loop.initializer);
+        self.visit_expression(&for_loop.condition);
+        self.visit_block(&for_loop.finalizer);
+        self.visit_block(&for_loop.body);
+    }
+
+    ///
+    /// Visits an expression.
+    ///
+    fn visit_expression(&mut self, expression: &Expression) {
+        self.walk_expression(expression);
+    }
+
+    ///
+    /// Dispatches the expression to the visitor method of its variant.
+    ///
+    fn walk_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::FunctionCall(inner) => self.visit_function_call(inner),
+            Expression::Identifier(inner) => self.visit_identifier(inner),
+            Expression::Literal(inner) => self.visit_literal(inner),
+        }
+    }
+
+    ///
+    /// Visits a function call.
+    ///
+    fn visit_function_call(&mut self, call: &FunctionCall) {
+        self.walk_function_call(call);
+    }
+
+    ///
+    /// Visits the function call arguments.
+    ///
+    fn walk_function_call(&mut self, call: &FunctionCall) {
+        for argument in call.arguments.iter() {
+            self.visit_expression(argument);
+        }
+    }
+
+    ///
+    /// Visits an identifier, either declared or used.
+    ///
+    fn visit_identifier(&mut self, _identifier: &Identifier) {}
+
+    ///
+    /// Visits a literal.
+    ///
+    fn visit_literal(&mut self, _literal: &Literal) {}
+}
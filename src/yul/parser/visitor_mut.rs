@@ -0,0 +1,283 @@
+//!
+//! The YUL syntax tree mutable visitor.
+//!
+
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::data::Data;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::case::Case;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+
+///
+/// The YUL syntax tree mutable visitor.
+///
+/// Mirrors the `Visitor` trait, but allows rewriting the nodes in place.
+///
+/// Each `visit_*` method defaults to the corresponding `walk_*` method, which visits the node
+/// children in the source code order. An implementation overrides the methods of the nodes it
+/// is interested in, and calls the `walk_*` method to continue the traversal into the children.
+///
+pub trait VisitorMut {
+    ///
+    /// Visits an object.
+    ///
+    fn visit_object(&mut self, object: &mut Object) {
+        self.walk_object(object);
+    }
+
+    ///
+    /// Visits the object children: the code, the inner object, the data sections and the
+    /// dependencies.
+    ///
+    fn walk_object(&mut self, object: &mut Object) {
+        self.visit_code(&mut object.code);
+        if let Some(ref mut inner) = object.object {
+            self.visit_object(inner);
+        }
+        for data in object.data.iter_mut() {
+            self.visit_data(data);
+        }
+        for dependency in object.dependencies.iter_mut() {
+            self.visit_object(dependency);
+        }
+    }
+
+    ///
+    /// Visits an object data section.
+    ///
+    fn visit_data(&mut self, _data: &mut Data) {}
+
+    ///
+    /// Visits a code section.
+    ///
+    fn visit_code(&mut self, code: &mut Code) {
+        self.walk_code(code);
+    }
+
+    ///
+    /// Visits the code section block.
+    ///
+    fn walk_code(&mut self, code: &mut Code) {
+        self.visit_block(&mut code.block);
+    }
+
+    ///
+    /// Visits a block.
+    ///
+    fn visit_block(&mut self, block: &mut Block) {
+        self.walk_block(block);
+    }
+
+    ///
+    /// Visits the block statements.
+    ///
+    fn walk_block(&mut self, block: &mut Block) {
+        for statement in block.statements.iter_mut() {
+            self.visit_statement(statement);
+        }
+    }
+
+    ///
+    /// Visits a statement.
+    ///
+    fn visit_statement(&mut self, statement: &mut Statement) {
+        self.walk_statement(statement);
+    }
+
+    ///
+    /// Dispatches the statement to the visitor method of its variant.
+    ///
+    fn walk_statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Object(inner) => self.visit_object(inner),
+            Statement::Code(inner) => self.visit_code(inner),
+            Statement::Block(inner) => self.visit_block(inner),
+            Statement::Expression(inner) => self.visit_expression(inner),
+            Statement::FunctionDefinition(inner) => self.visit_function_definition(inner),
+            Statement::VariableDeclaration(inner) => self.visit_variable_declaration(inner),
+            Statement::Assignment(inner) => self.visit_assignment(inner),
+            Statement::IfConditional(inner) => self.visit_if_conditional(inner),
+            Statement::Switch(inner) => self.visit_switch(inner),
+            Statement::ForLoop(inner) => self.visit_for_loop(inner),
+            Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+        }
+    }
+
+    ///
+    /// Visits a function definition.
+    ///
+    fn visit_function_definition(&mut self, definition: &mut FunctionDefinition) {
+        self.walk_function_definition(definition);
+    }
+
+    ///
+    /// Visits the function arguments, return variables and body.
+    ///
+    fn walk_function_definition(&mut self, definition: &mut FunctionDefinition) {
+        for identifier in definition.arguments.iter_mut() {
+            self.visit_identifier(identifier);
+        }
+        for identifier in definition.result.iter_mut() {
+            self.visit_identifier(identifier);
+        }
+        self.visit_block(&mut definition.body);
+    }
+
+    ///
+    /// Visits a variable declaration.
+    ///
+    fn visit_variable_declaration(&mut self, declaration: &mut VariableDeclaration) {
+        self.walk_variable_declaration(declaration);
+    }
+
+    ///
+    /// Visits the declared variables and the initializing expression.
+    ///
+    fn walk_variable_declaration(&mut self, declaration: &mut VariableDeclaration) {
+        for identifier in declaration.bindings.iter_mut() {
+            self.visit_identifier(identifier);
+        }
+        if let Some(ref mut expression) = declaration.expression {
+            self.visit_expression(expression);
+        }
+    }
+
+    ///
+    /// Visits an assignment.
+    ///
+    fn visit_assignment(&mut self, assignment: &mut Assignment) {
+        self.walk_assignment(assignment);
+    }
+
+    ///
+    /// Visits the assigned variables and the initializing expression.
+    ///
+    fn walk_assignment(&mut self, assignment: &mut Assignment) {
+        for identifier in assignment.bindings.iter_mut() {
+            self.visit_identifier(identifier);
+        }
+        self.visit_expression(&mut assignment.initializer);
+    }
+
+    ///
+    /// Visits an `if` statement.
+    ///
+    fn visit_if_conditional(&mut self, conditional: &mut IfConditional) {
+        self.walk_if_conditional(conditional);
+    }
+
+    ///
+    /// Visits the condition and the conditional block.
+    ///
+    fn walk_if_conditional(&mut self, conditional: &mut IfConditional) {
+        self.visit_expression(&mut conditional.condition);
+        self.visit_block(&mut conditional.block);
+    }
+
+    ///
+    /// Visits a `switch` statement.
+    ///
+    fn visit_switch(&mut self, switch: &mut Switch) {
+        self.walk_switch(switch);
+    }
+
+    ///
+    /// Visits the matched expression, the cases and the default block.
+    ///
+    fn walk_switch(&mut self, switch: &mut Switch) {
+        self.visit_expression(&mut switch.expression);
+        for case in switch.cases.iter_mut() {
+            self.visit_case(case);
+        }
+        if let Some(ref mut default) = switch.default {
+            self.visit_block(default);
+        }
+    }
+
+    ///
+    /// Visits a `switch` case.
+    ///
+    fn visit_case(&mut self, case: &mut Case) {
+        self.walk_case(case);
+    }
+
+    ///
+    /// Visits the case literal and block.
+    ///
+    fn walk_case(&mut self, case: &mut Case) {
+        self.visit_literal(&mut case.literal);
+        self.visit_block(&mut case.block);
+    }
+
+    ///
+    /// Visits a `for` statement.
+    ///
+    fn visit_for_loop(&mut self, for_loop: &mut ForLoop) {
+        self.walk_for_loop(for_loop);
+    }
+
+    ///
+    /// Visits the initializer, the condition, the finalizer and the body.
+    ///
+    fn walk_for_loop(&mut self, for_loop: &mut ForLoop) {
+        self.visit_block(&mut for_loop.initializer);
+        self.visit_expression(&mut for_loop.condition);
+        self.visit_block(&mut for_loop.finalizer);
+        self.visit_block(&mut for_loop.body);
+    }
+
+    ///
+    /// Visits an expression.
+    ///
+    fn visit_expression(&mut self, expression: &mut Expression) {
+        self.walk_expression(expression);
+    }
+
+    ///
+    /// Dispatches the expression to the visitor method of its variant.
+    ///
+    fn walk_expression(&mut self, expression: &mut Expression) {
+        match expression {
+            Expression::FunctionCall(inner) => self.visit_function_call(inner),
+            Expression::Identifier(inner) => self.visit_identifier(inner),
+            Expression::Literal(inner) => self.visit_literal(inner),
+        }
+    }
+
+    ///
+    /// Visits a function call.
+    ///
+    fn visit_function_call(&mut self, call: &mut FunctionCall) {
+        self.walk_function_call(call);
+    }
+
+    ///
+    /// Visits the function call arguments.
+    ///
+    fn walk_function_call(&mut self, call: &mut FunctionCall) {
+        for argument in call.arguments.iter_mut() {
+            self.visit_expression(argument);
+        }
+    }
+
+    ///
+    /// Visits an identifier, either declared or used.
+    ///
+    fn visit_identifier(&mut self, _identifier: &mut Identifier) {}
+
+    ///
+    /// Visits a literal.
+    ///
+    fn visit_literal(&mut self, _literal: &mut Literal) {}
+}
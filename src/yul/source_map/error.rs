@@ -0,0 +1,49 @@
+//!
+//! The Yul lowering error with the Solidity source location.
+//!
+
+use std::fmt;
+
+use crate::yul::lexer::token::location::Location;
+
+///
+/// The Yul lowering error with the Solidity source location.
+///
+/// Wraps the errors raised while lowering an annotated Yul statement, so the Solidity source
+/// location can be resolved by the caller, which has the source code at hand.
+///
+#[derive(Debug)]
+pub struct Error {
+    /// The location of the statement being lowered.
+    pub location: Location,
+    /// The lowering error.
+    pub inner: anyhow::Error,
+}
+
+impl Error {
+    ///
+    /// Wraps the `error` raised while lowering the statement at the `location`.
+    ///
+    /// The error is returned unchanged if the statement is not annotated, or if it has been
+    /// wrapped by a nested statement, whose location is more precise.
+    ///
+    pub fn wrap(location: Location, error: anyhow::Error) -> anyhow::Error {
+        if location.source.is_none() || error.is::<Self>() {
+            return error;
+        }
+
+        Self {
+            location,
+            inner: error,
+        }
+        .into()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl std::error::Error for Error {}
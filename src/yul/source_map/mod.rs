@@ -0,0 +1,115 @@
+//!
+//! The Solidity source map.
+//!
+
+pub mod error;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
+
+use self::error::Error;
+
+///
+/// The Solidity source map.
+///
+/// Resolves the `@src` annotations of the Yul code to the Solidity file lines and columns, so
+/// the Yul diagnostics can point at the code the developers have written.
+///
+#[derive(Debug, Default, Clone)]
+pub struct SourceMap {
+    /// The Solidity source code, by the file paths.
+    sources: HashMap<String, String>,
+}
+
+impl SourceMap {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(sources: HashMap<String, String>) -> Self {
+        Self { sources }
+    }
+
+    ///
+    /// Resolves the `location` to the `path:line:column` string.
+    ///
+    /// The `paths` are the source file paths by their indexes, as specified by the `@use-src`
+    /// annotation of the object. If the source code is unknown, the location is resolved to the
+    /// path and the byte range.
+    ///
+    pub fn resolve(&self, paths: &BTreeMap<usize, String>, location: SourceLocation) -> String {
+        let path = match paths.get(&location.file) {
+            Some(path) => path,
+            None => return format!("<unknown source {}>", location),
+        };
+
+        match self
+            .sources
+            .get(path)
+            .and_then(|source| source.get(..location.start))
+        {
+            Some(prefix) => {
+                let line = prefix.matches('\n').count() + 1;
+                let line_start = prefix
+                    .rfind('\n')
+                    .map(|index| index + 1)
+                    .unwrap_or_default();
+                let column = prefix[line_start..].chars().count() + 1;
+                format!("{}:{}:{}", path, line, column)
+            }
+            None => format!(
+                "{}:{}..{}",
+                path,
+                location.start,
+                location.start + location.length
+            ),
+        }
+    }
+
+    ///
+    /// Appends the Solidity source location to the diagnostic `message` about the Yul code at
+    /// the `location`, if the code is annotated.
+    ///
+    pub fn annotate(
+        &self,
+        paths: &BTreeMap<usize, String>,
+        location: Location,
+        message: String,
+    ) -> String {
+        self.annotate_source(paths, location.source, message)
+    }
+
+    ///
+    /// Appends the Solidity source `location` to the diagnostic `message`, if it is known.
+    ///
+    pub fn annotate_source(
+        &self,
+        paths: &BTreeMap<usize, String>,
+        location: Option<SourceLocation>,
+        message: String,
+    ) -> String {
+        match location {
+            Some(location) => format!("{}\n --> {}", message, self.resolve(paths, location)),
+            None => message,
+        }
+    }
+
+    ///
+    /// Appends the Solidity source location to the Yul lowering `error`, if it has been raised
+    /// by an annotated statement.
+    ///
+    pub fn annotate_error(
+        &self,
+        paths: &BTreeMap<usize, String>,
+        error: anyhow::Error,
+    ) -> anyhow::Error {
+        match error.downcast::<Error>() {
+            Ok(error) => {
+                anyhow::anyhow!(self.annotate(paths, error.location.clone(), error.to_string()))
+            }
+            Err(error) => error,
+        }
+    }
+}
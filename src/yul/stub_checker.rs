@@ -0,0 +1,107 @@
+//!
+//! The Yul stubbed builtin checker.
+//!
+
+use std::fmt;
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::visitor::Visitor;
+
+///
+/// The Yul stubbed builtin checker.
+///
+/// Finds the calls to the builtins which have no zkEVM equivalent, and are compiled to constants
+/// or no-ops, so they can be rejected in the strict mode.
+///
+/// Only the deploy and runtime code of the object are checked, as the dependency objects are
+/// compiled, and therefore checked, as separate contracts.
+///
+#[derive(Debug, Default)]
+pub struct StubChecker {
+    /// The identifier of the object being visited.
+    object: String,
+    /// The names of the functions being visited, the innermost last.
+    functions: Vec<String>,
+    /// The stubbed builtin calls found so far.
+    stubs: Vec<Stub>,
+}
+
+///
+/// The stubbed builtin call.
+///
+#[derive(Debug, Clone)]
+pub struct Stub {
+    /// The call location.
+    pub location: Location,
+    /// The builtin name.
+    pub name: Name,
+    /// The identifier of the object the call is in.
+    pub object: String,
+    /// The name of the function the call is in, if any.
+    pub function: Option<String>,
+}
+
+impl StubChecker {
+    ///
+    /// Returns the stubbed builtin calls in the deploy and runtime code of the `object`.
+    ///
+    pub fn check(object: &Object) -> Vec<Stub> {
+        let mut checker = Self::default();
+        checker.visit_object(object);
+        checker.stubs
+    }
+}
+
+impl Visitor for StubChecker {
+    fn visit_object(&mut self, object: &Object) {
+        self.object = object.identifier.to_owned();
+        self.visit_code(&object.code);
+        if let Some(ref runtime) = object.object {
+            self.visit_object(runtime);
+        }
+    }
+
+    fn visit_function_definition(&mut self, definition: &FunctionDefinition) {
+        self.functions.push(definition.name.to_owned());
+        self.walk_function_definition(definition);
+        self.functions.pop();
+    }
+
+    fn visit_function_call(&mut self, call: &FunctionCall) {
+        if call.is_stubbed() {
+            self.stubs.push(Stub {
+                location: call.location.clone(),
+                name: call.name.to_owned(),
+                object: self.object.to_owned(),
+                function: self.functions.last().cloned(),
+            });
+        }
+        self.walk_function_call(call);
+    }
+}
+
+impl fmt::Display for Stub {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let qualifier = match self.name {
+            Name::DataCopy => " with a source other than a data section or a child object",
+            _ => "",
+        };
+        write!(
+            f,
+            "{} The `{}` builtin{} has no zkEVM equivalent and is compiled to a stub, ",
+            self.location, self.name, qualifier
+        )?;
+        match self.function {
+            Some(ref function) => write!(
+                f,
+                "in the function `{}` of the object `{}`",
+                function, self.object
+            ),
+            None => write!(f, "in the object `{}`", self.object),
+        }
+    }
+}
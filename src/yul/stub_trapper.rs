@@ -0,0 +1,32 @@
+//!
+//! The Yul stubbed builtin trapper.
+//!
+
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::visitor_mut::VisitorMut;
+
+///
+/// The Yul stubbed builtin trapper.
+///
+/// Marks the calls to the builtins which have no zkEVM equivalent, so they are compiled to
+/// reverts identifying the builtins instead of constants or no-ops.
+///
+#[derive(Debug, Default)]
+pub struct StubTrapper {}
+
+impl StubTrapper {
+    ///
+    /// Marks the stubbed builtin calls in the object tree.
+    ///
+    pub fn trap(object: &mut Object) {
+        Self::default().visit_object(object);
+    }
+}
+
+impl VisitorMut for StubTrapper {
+    fn visit_function_call(&mut self, call: &mut FunctionCall) {
+        call.is_trapped = call.is_stubbed();
+        self.walk_function_call(call);
+    }
+}
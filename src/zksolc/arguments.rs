@@ -77,9 +77,46 @@ pub struct Arguments {
     pub standard_json: bool,
 
     /// Switch to Yul mode.
+    /// The Yul files are compiled as written, without being passed
+    /// through solc, and may contain several top-level objects.
     #[structopt(long = "yul")]
     pub yul: bool,
 
+    /// Compile only the given top-level object of each Yul file
+    /// as the deploy code. By default, all top-level objects are
+    /// compiled as separate contracts.
+    #[structopt(long = "yul-deploy-object")]
+    pub yul_deploy_object: Option<String>,
+
+    /// Use the given object as the runtime code of the Yul
+    /// contracts. It is looked up among the inner objects of the
+    /// deploy code object, and then among the top-level objects.
+    /// By default, the inner object named `<deploy>_deployed`
+    /// is used, following the solc naming.
+    #[structopt(long = "yul-runtime-object")]
+    pub yul_runtime_object: Option<String>,
+
+    /// Reject the operations which have no zkEVM equivalent, like
+    /// `callcode`, `selfdestruct` or `pc`, instead of compiling them
+    /// to constants or no-ops. In Standard JSON mode, can also be
+    /// enabled with the `settings.strict` field.
+    #[structopt(long = "strict")]
+    pub strict: bool,
+
+    /// Compile the operations which have no zkEVM equivalent to
+    /// reverts with the `Error(string)` reason naming the operation,
+    /// instead of constants or no-ops. The contracts never executing
+    /// them are not affected.
+    #[structopt(long = "trap-unsupported")]
+    pub trap_unsupported: bool,
+
+    /// The target EVM version, which gates the opcodes introduced by
+    /// the newer hard forks, like `push0` or `mcopy`, and is passed to
+    /// solc. Defaults to the newest version. In Standard JSON mode, can
+    /// also be set with the `settings.evmVersion` field.
+    #[structopt(long = "evm-version")]
+    pub evm_version: Option<String>,
+
     /// Output ABI specification of the contracts.
     #[structopt(long = "abi")]
     pub output_abi: bool,
@@ -96,6 +133,15 @@ pub struct Arguments {
     #[structopt(long = "bin")]
     pub output_binary: bool,
 
+    /// Run the Yul optimizer passes before the LLVM IR generation.
+    /// Available passes: inlining, constant-folding,
+    /// dead-code-elimination, unused-removal. A list of passes
+    /// can be supplied by separating them with a comma, and
+    /// `all` runs all of them in the listed order. Used together
+    /// with --dump-yul, dumps the Yul code after each pass.
+    #[structopt(long = "yul-passes")]
+    pub yul_passes: Option<String>,
+
     /// Dump the Yul Intermediate Representation (IR) of all contracts.
     #[structopt(long = "dump-yul")]
     pub dump_yul: bool,
@@ -129,6 +175,12 @@ impl Arguments {
     /// Validates the arguments.
     ///
     pub fn validate(&self) -> anyhow::Result<()> {
+        if self.strict && self.trap_unsupported {
+            anyhow::bail!(
+                "The following options are mutually exclusive: --strict, --trap-unsupported."
+            );
+        }
+
         if self.yul {
             if self.combined_json.is_some() {
                 anyhow::bail!("The following options are invalid in Yul mode: --combined-json.");
@@ -142,6 +194,17 @@ impl Arguments {
             if self.output_hashes {
                 anyhow::bail!("The following options are invalid in Yul mode: --hashes.");
             }
+        } else {
+            if self.yul_deploy_object.is_some() {
+                anyhow::bail!(
+                    "The following options are only valid in Yul mode: --yul-deploy-object."
+                );
+            }
+            if self.yul_runtime_object.is_some() {
+                anyhow::bail!(
+                    "The following options are only valid in Yul mode: --yul-runtime-object."
+                );
+            }
         }
 
         Ok(())
@@ -4,6 +4,7 @@
 
 pub mod arguments;
 
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::RwLock;
 
@@ -37,6 +38,21 @@ fn main_inner() -> anyhow::Result<()> {
         arguments.dump_assembly,
     );
 
+    let yul_optimizer = compiler_solidity::YulOptimizer::new(
+        arguments
+            .yul_passes
+            .as_deref()
+            .map(compiler_solidity::YulOptimizerPass::parse_list)
+            .transpose()?
+            .unwrap_or_default(),
+    );
+
+    let evm_version = arguments
+        .evm_version
+        .as_deref()
+        .map(compiler_solidity::EVMVersion::from_str)
+        .transpose()?;
+
     for path in arguments.input_files.iter_mut() {
         *path = path.canonicalize()?;
     }
@@ -53,66 +69,101 @@ fn main_inner() -> anyhow::Result<()> {
         compiler_solidity::SolcPipeline::EVM
     };
 
-    let output_selection = compiler_solidity::SolcStandardJsonInputSettings::get_output_selection(
-        arguments
-            .input_files
-            .iter()
-            .map(|path| path.to_string_lossy().to_string())
-            .collect(),
-        pipeline,
-    );
-    let solc_input = if arguments.standard_json {
-        let mut input: compiler_solidity::SolcStandardJsonInput =
-            serde_json::from_reader(std::io::BufReader::new(std::io::stdin()))?;
-        input.settings.output_selection = output_selection;
-        input
+    let (project, solc_output) = if arguments.yul {
+        let layout = compiler_solidity::YulObjectLayout::new(
+            arguments.yul_deploy_object,
+            arguments.yul_runtime_object,
+        );
+
+        compiler_solidity::initialize_target();
+        let project = compiler_solidity::Project::try_from_yul_paths(
+            arguments.input_files.as_slice(),
+            solc_version,
+            &layout,
+            &yul_optimizer,
+            compiler_solidity::StubMode::new(arguments.strict, arguments.trap_unsupported)?,
+            evm_version.unwrap_or_default(),
+            dump_flags.as_slice(),
+        )?;
+        (project, None)
     } else {
-        let language = if arguments.yul {
-            compiler_solidity::SolcStandardJsonInputLanguage::Yul
+        let output_selection =
+            compiler_solidity::SolcStandardJsonInputSettings::get_output_selection(
+                arguments
+                    .input_files
+                    .iter()
+                    .map(|path| path.to_string_lossy().to_string())
+                    .collect(),
+                pipeline,
+            );
+        let mut solc_input = if arguments.standard_json {
+            let mut input: compiler_solidity::SolcStandardJsonInput =
+                serde_json::from_reader(std::io::BufReader::new(std::io::stdin()))?;
+            input.settings.output_selection = output_selection;
+            input
         } else {
-            compiler_solidity::SolcStandardJsonInputLanguage::Solidity
+            compiler_solidity::SolcStandardJsonInput::try_from_paths(
+                compiler_solidity::SolcStandardJsonInputLanguage::Solidity,
+                arguments.input_files.as_slice(),
+                arguments.libraries,
+                output_selection,
+                true,
+            )?
         };
-        compiler_solidity::SolcStandardJsonInput::try_from_paths(
-            language,
-            arguments.input_files.as_slice(),
-            arguments.libraries,
-            output_selection,
-            true,
-        )?
-    };
 
-    let libraries = solc_input.settings.libraries.clone().unwrap_or_default();
-    let mut solc_output = solc.standard_json(
-        solc_input,
-        arguments.base_path,
-        arguments.include_paths,
-        arguments.allow_paths,
-    )?;
-
-    if let Some(errors) = solc_output.errors.as_deref() {
-        let mut cannot_compile = false;
-        for error in errors.iter() {
-            if error.severity.as_str() == "error" {
-                cannot_compile = true;
-                if arguments.standard_json {
-                    serde_json::to_writer(std::io::stdout(), &solc_output)?;
-                    return Ok(());
+        if evm_version.is_some() {
+            solc_input.settings.evm_version = evm_version;
+        }
+        let evm_version = solc_input.settings.evm_version.unwrap_or_default();
+
+        let libraries = solc_input.settings.libraries.clone().unwrap_or_default();
+        let stub_mode = compiler_solidity::StubMode::new(
+            arguments.strict || solc_input.settings.strict,
+            arguments.trap_unsupported,
+        )?;
+        let source_map = compiler_solidity::YulSourceMap::new(solc_input.source_code());
+        let mut solc_output = solc.standard_json(
+            solc_input,
+            arguments.base_path,
+            arguments.include_paths,
+            arguments.allow_paths,
+        )?;
+
+        if let Some(errors) = solc_output.errors.as_deref() {
+            let mut cannot_compile = false;
+            for error in errors.iter() {
+                if error.severity.as_str() == "error" {
+                    cannot_compile = true;
+                    if arguments.standard_json {
+                        serde_json::to_writer(std::io::stdout(), &solc_output)?;
+                        return Ok(());
+                    }
+                }
+
+                if !arguments.standard_json && arguments.combined_json.is_none() {
+                    eprintln!("{}", error);
                 }
             }
 
-            if !arguments.standard_json && arguments.combined_json.is_none() {
-                eprintln!("{}", error);
+            if cannot_compile {
+                anyhow::bail!("Error(s) found. Compilation aborted");
             }
         }
 
-        if cannot_compile {
-            anyhow::bail!("Error(s) found. Compilation aborted");
-        }
-    }
+        compiler_solidity::initialize_target();
+        let project = solc_output.try_into_project(
+            libraries,
+            pipeline,
+            solc_version,
+            source_map,
+            &yul_optimizer,
+            stub_mode,
+            evm_version,
+            dump_flags.as_slice(),
+        )?;
+        (project, Some(solc_output))
+    };
 
-    compiler_solidity::initialize_target();
-    let project =
-        solc_output.try_into_project(libraries, pipeline, solc_version, dump_flags.as_slice())?;
     let project = Arc::new(RwLock::new(project));
     compiler_solidity::Project::compile_all(project.clone(), arguments.optimize, dump_flags)?;
     let project = Arc::try_unwrap(project)
@@ -120,7 +171,14 @@ fn main_inner() -> anyhow::Result<()> {
         .into_inner()
         .unwrap();
 
+    if !arguments.standard_json && arguments.combined_json.is_none() {
+        for warning in project.warnings().into_iter() {
+            eprintln!("{}", warning);
+        }
+    }
+
     if arguments.standard_json {
+        let mut solc_output = solc_output.expect("Always exists outside Yul mode");
         project.write_to_standard_json(&mut solc_output)?;
         serde_json::to_writer(std::io::stdout(), &solc_output)?;
         return Ok(());